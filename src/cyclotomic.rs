@@ -1,16 +1,24 @@
 use core::marker::PhantomData;
 use std::fmt::{Debug, Formatter};
-use std::ops::{Add, AddAssign, Mul, Rem, SubAssign};
+use std::ops::{Add, AddAssign, Mul, Neg, Rem, Sub, SubAssign};
+use std::str::FromStr;
 
 use generic_array::{ArrayLength, GenericArray};
 use num_bigint::{BigInt, BigUint};
-use num_traits::Zero;
-use typenum::{PowerOfTwo, Unsigned};
+use num_integer::Integer;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use typenum::{IsGreaterOrEqual, PowerOfTwo, True, Unsigned};
 
-use crate::traits::{Characteristic, Element, FieldElement, RlweRing, Vector};
+use crate::error::RlweError;
+use crate::montgomery::MontInt;
+use crate::traits::{
+    Characteristic, Element, ElementSumExt, FieldElement, RlweRing, Vector,
+};
 
 /// An integer modulo the given characteristic. For positive characteristic `p`,
-/// a ModularBigInt is an integer in the set (-p/2, p/2].
+/// a ModularBigInt is an integer in the set (-p/2, p/2]. Every operation
+/// returns a representant in that set, which makes the derived `PartialEq`
+/// agree with equality modulo `p`.
 #[derive(Clone, PartialEq)]
 pub struct ModularBigInt<C: Characteristic> {
     representant: BigInt,
@@ -54,7 +62,9 @@ impl<C: Characteristic> Add for ModularBigInt<C> {
         Self {
             representant: val,
             modulus: PhantomData,
-        } % C::to_biguint()
+        }
+        .into_reduced_once()
+        .checked()
     }
 }
 
@@ -64,7 +74,23 @@ impl<C: Characteristic> AddAssign for ModularBigInt<C> {
         *self = Self {
             representant: val,
             modulus: PhantomData,
-        } % C::to_biguint()
+        }
+        .into_reduced_once()
+        .checked()
+    }
+}
+
+impl<C: Characteristic> Sub for ModularBigInt<C> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        let val = self.representant.clone() - other.representant.clone();
+        Self {
+            representant: val,
+            modulus: PhantomData,
+        }
+        .into_reduced_once()
+        .checked()
     }
 }
 
@@ -74,7 +100,9 @@ impl<C: Characteristic> SubAssign for ModularBigInt<C> {
         *self = Self {
             representant: val,
             modulus: PhantomData,
-        } % C::to_biguint()
+        }
+        .into_reduced_once()
+        .checked()
     }
 }
 
@@ -83,13 +111,37 @@ impl<C: Characteristic> Mul for ModularBigInt<C> {
 
     fn mul(self, other: Self) -> Self::Output {
         let val = self.representant.clone() * other.representant.clone();
-        Self {
+        (Self {
             representant: val,
             modulus: PhantomData,
+        } % C::to_biguint())
+        .checked()
+    }
+}
+
+impl<C: Characteristic> One for ModularBigInt<C> {
+    fn one() -> Self {
+        Self {
+            representant: One::one(),
+            modulus: PhantomData,
         } % C::to_biguint()
     }
 }
 
+impl<C: Characteristic> Neg for ModularBigInt<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        (Self {
+            representant: -self.representant,
+            modulus: PhantomData,
+        } % C::to_biguint())
+        .checked()
+    }
+}
+
+/// Balanced reduction into (-m/2, m/2]. A zero modulus leaves the value
+/// untouched, so in characteristic zero all arithmetic is exact.
 impl<C: Characteristic> Rem<BigUint> for ModularBigInt<C> {
     type Output = Self;
 
@@ -97,24 +149,321 @@ impl<C: Characteristic> Rem<BigUint> for ModularBigInt<C> {
         if modulus == Zero::zero() {
             return self;
         }
-        let m: BigInt = modulus.into();
-        let right = m.clone() / 2_u32;
-        let left = right.clone() - m.clone();
-        let rep = self.representant % m.clone();
-        let true_rep = if rep <= left {
-            rep + m
-        } else if rep > right {
-            rep - m
+        count_reduction();
+        ModularBigInt::<C> {
+            representant: balanced_rem(&self.representant, &modulus),
+            modulus: PhantomData,
+        }
+    }
+}
+
+/// Returns the representative of `x` modulo `modulus` in the balanced range
+/// `(-m/2, m/2]`. A zero modulus returns `x` unchanged.
+pub fn balanced_rem(x: &BigInt, modulus: &BigUint) -> BigInt {
+    if modulus.is_zero() {
+        return x.clone();
+    }
+    let m: BigInt = modulus.clone().into();
+    let right = m.clone() / 2_u32;
+    let left = right.clone() - m.clone();
+    let rep = x % m.clone();
+    if rep <= left {
+        rep + m
+    } else if rep > right {
+        rep - m
+    } else {
+        rep
+    }
+}
+
+impl<C: Characteristic> ModularBigInt<C> {
+    /// The balanced representant.
+    pub(crate) fn representant(&self) -> &BigInt {
+        &self.representant
+    }
+
+    /// Raises the element to the given power.
+    pub fn pow(&self, exp: u64) -> Self {
+        let ch = C::to_biguint();
+        let val = if ch.is_zero() {
+            num_traits::Pow::pow(&self.representant, exp)
         } else {
-            rep
+            self.representant.modpow(&exp.into(), &ch.clone().into())
         };
-        ModularBigInt::<C> {
-            representant: true_rep,
+        (Self {
+            representant: val,
+            modulus: PhantomData,
+        } % ch)
+            .checked()
+    }
+
+    /// Multiplies the element by `2ᵏ`.
+    ///
+    /// The representant is shifted left and then folded back. Moduli of the
+    /// form `q = 2ᵇ - c` with `|c| ≤ 2^(b/2)`, such as Mersenne primes
+    /// (`2⁶¹ - 1`), Fermat primes (`2⁸ + 1`) and other Solinas primes, use
+    /// `2ᵇ ≡ c` to replace the division by `q` with shifts and a small
+    /// multiplication. Other moduli, and exponents larger than twice the
+    /// modulus size, go through a regular modular multiplication.
+    pub fn mul_pow2(&self, k: u32) -> Self {
+        let ch = C::to_biguint();
+        if ch.is_zero() {
+            return Self {
+                representant: &self.representant << k,
+                modulus: PhantomData,
+            };
+        }
+        let two: Self = BigInt::from(2).into();
+        let form = special_form(&ch);
+        let (b, c) = match form {
+            Some(form) if u64::from(k) <= 2 * ch.bits() => form,
+            _ => return self.clone() * two.pow(k.into()),
+        };
+        let mut x: BigInt = &self.representant << k;
+        while x.bits() > b + 1 {
+            let high: BigInt = &x >> b;
+            let low: BigInt = &x - (&high << b);
+            x = low + &c * high;
+        }
+        (Self {
+            representant: x,
             modulus: PhantomData,
+        } % ch)
+            .checked()
+    }
+
+    /// Converts the element to Montgomery form.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the characteristic is even, in particular for `CharZero`.
+    pub fn to_montgomery(&self) -> MontInt<C> {
+        let ch: BigInt = C::to_biguint().into();
+        let residue = if self.representant.is_negative() {
+            &self.representant + ch
+        } else {
+            self.representant.clone()
+        };
+        MontInt::from_residue(residue.to_biguint().unwrap_or_default())
+    }
+
+    /// Converts the element back from Montgomery form.
+    pub fn from_montgomery(m: &MontInt<C>) -> Self {
+        BigInt::from(m.to_residue()).into()
+    }
+
+    /// Reduces a representant lying at most one modulus away from the balanced
+    /// range (e.g. in `[-3q/2, 3q/2]`) with a single addition or subtraction
+    /// of `q`, falling back to the full `Rem` if it is further off.
+    pub fn reduce_once(&self) -> Self {
+        self.clone().into_reduced_once()
+    }
+
+    fn into_reduced_once(mut self) -> Self {
+        let ch = C::to_biguint();
+        if ch.is_zero() {
+            return self;
+        }
+        let m: BigInt = ch.clone().into();
+        let right = &m / 2_u32;
+        let left = &right - &m;
+        if self.representant > right {
+            self.representant -= &m;
+        } else if self.representant <= left {
+            self.representant += &m;
+        } else {
+            return self;
+        }
+        if self.representant > right || self.representant <= left {
+            return self % ch;
         }
+        self
     }
+
+    /// Negates the element in place, without cloning the representant.
+    pub fn negate_mut(&mut self) {
+        self.representant = -std::mem::take(&mut self.representant);
+        // Only -p/2, for even p, falls outside of (-p/2, p/2].
+        let ch: BigInt = C::to_biguint().into();
+        if !ch.is_zero() && &self.representant * 2 == -ch {
+            self.representant = -std::mem::take(&mut self.representant);
+        }
+        debug_assert_balanced(self);
+    }
+
+    /// Passes the value through, asserting first that it is balanced when
+    /// the `debug-invariants` feature is enabled in a debug build.
+    fn checked(self) -> Self {
+        debug_assert_balanced(&self);
+        self
+    }
+
+    /// Returns a square root of the element, or `None` if it is not a square.
+    /// For an odd prime `p ≡ 3 mod 4` the root is `x^((p+1)/4)`; other primes
+    /// use Tonelli–Shanks. In characteristic zero only perfect squares have
+    /// a root, which is then the nonnegative one.
+    ///
+    /// A positive characteristic is assumed to be prime. For a composite one
+    /// both searches of Tonelli–Shanks are cut short where they would not
+    /// terminate, so `None` may be returned for a square; a returned root is
+    /// always checked to square to the element.
+    pub fn sqrt(&self) -> Option<Self> {
+        let ch = C::to_biguint();
+        let from = |representant: BigInt| {
+            Self {
+                representant,
+                modulus: PhantomData,
+            } % ch.clone()
+        };
+        if ch.is_zero() {
+            if self.representant.is_negative() {
+                return None;
+            }
+            let root = num_integer::Roots::sqrt(&self.representant);
+            return (&root * &root == self.representant).then(|| from(root));
+        }
+        if self.is_zero() || ch == BigUint::from(2_u32) {
+            return Some(self.clone());
+        }
+        let p: BigInt = ch.clone().into();
+        let one = BigInt::one();
+        let x = self.representant.mod_floor(&p);
+        let pow = |b: &BigInt, e: &BigInt| b.modpow(e, &p);
+        let checked = |r: BigInt| ((&r * &r) % &p == x).then(|| from(r));
+        // Euler's criterion.
+        if pow(&x, &((&p - 1_u32) >> 1)) != one {
+            return None;
+        }
+        if (&p % 4_u32) == BigInt::from(3) {
+            return checked(pow(&x, &((&p + 1_u32) >> 2)));
+        }
+        // Write p - 1 = q·2ˢ with q odd, and find a non-residue z.
+        let s = (&p - 1_u32).trailing_zeros().unwrap_or_default();
+        let q: BigInt = (&p - 1_u32) >> s;
+        let mut z = BigInt::from(2);
+        while pow(&z, &((&p - 1_u32) >> 1)) == one {
+            z += 1_u32;
+            if z >= p {
+                return None;
+            }
+        }
+        let mut m = s;
+        let mut c = pow(&z, &q);
+        let mut t = pow(&x, &q);
+        let mut r = pow(&x, &((&q + 1_u32) >> 1));
+        while t != one {
+            // The least i with t^(2ⁱ) = 1; i < m since t has order 2ᵐ at most,
+            // unless p is composite. For an even p, m is already zero.
+            let mut i = 0;
+            let mut t2 = t.clone();
+            while t2 != one {
+                t2 = (&t2 * &t2) % &p;
+                i += 1;
+                if i >= m {
+                    return None;
+                }
+            }
+            let b = pow(&c, &(BigInt::one() << (m - i - 1)));
+            m = i;
+            c = (&b * &b) % &p;
+            t = (t * &c) % &p;
+            r = (r * b) % &p;
+        }
+        checked(r)
+    }
+
+    /// Returns the multiplicative inverse of the element, if it exists. For
+    /// positive characteristic `p` (assumed prime) it is computed as `x^(p-2)`.
+    pub fn inverse(&self) -> Option<Self> {
+        let ch = C::to_biguint();
+        if self.is_zero() {
+            return None;
+        }
+        if ch.is_zero() {
+            let one = BigInt::one();
+            return if self.representant == one || self.representant == -one {
+                Some(self.clone())
+            } else {
+                None
+            };
+        }
+        let exp: BigInt = (ch.clone() - 2_u32).into();
+        let val = self.representant.modpow(&exp, &ch.clone().into());
+        Some(
+            Self {
+                representant: val,
+                modulus: PhantomData,
+            } % ch,
+        )
+    }
+}
+
+#[cfg(feature = "reduction-count")]
+thread_local! {
+    static REDUCTIONS: std::cell::Cell<u64> = const { std::cell::Cell::new(0) };
+}
+
+/// Returns the number of divisions performed by `Rem<BigUint>` in the
+/// current thread since it started or since the last
+/// `reset_reduction_count`. Every division counts, whether or not it changes
+/// the representant. The single `±q` corrections of additions and
+/// subtractions are not divisions and are not counted, nor is anything in
+/// characteristic zero.
+///
+/// The counter is thread-local rather than a process-wide atomic, so that
+/// counting does not contend across threads and tests running in parallel
+/// do not see each other's reductions.
+#[cfg(feature = "reduction-count")]
+pub fn reduction_count() -> u64 {
+    REDUCTIONS.with(|count| count.get())
+}
+
+/// Sets the reduction counter of the current thread back to zero.
+#[cfg(feature = "reduction-count")]
+pub fn reset_reduction_count() {
+    REDUCTIONS.with(|count| count.set(0));
+}
+
+#[cfg(feature = "reduction-count")]
+fn count_reduction() {
+    REDUCTIONS.with(|count| count.set(count.get() + 1));
+}
+
+#[cfg(not(feature = "reduction-count"))]
+#[inline(always)]
+fn count_reduction() {}
+
+/// Writes `q` as `2ᵇ - c` with `|c| ≤ 2^(b/2)`, trying `b` equal to the bit
+/// length of `q` and one less. Returns `None` if neither fits.
+fn special_form(q: &BigUint) -> Option<(u64, BigInt)> {
+    let q: BigInt = q.clone().into();
+    [q.bits(), q.bits() - 1]
+        .into_iter()
+        .map(|b| (b, (BigInt::one() << b) - &q))
+        .find(|(b, c)| 2 * c.bits() <= *b)
+}
+
+/// Asserts that the representant lies in (-p/2, p/2]. Compiled out unless
+/// the `debug-invariants` feature is enabled, and in release builds.
+#[cfg(feature = "debug-invariants")]
+fn debug_assert_balanced<C: Characteristic>(x: &ModularBigInt<C>) {
+    let ch: BigInt = C::to_biguint().into();
+    if ch.is_zero() {
+        return;
+    }
+    let doubled = &x.representant * 2;
+    debug_assert!(
+        doubled > -&ch && doubled <= ch,
+        "representant {} is not balanced modulo {}",
+        x.representant,
+        ch
+    );
 }
 
+#[cfg(not(feature = "debug-invariants"))]
+#[inline(always)]
+fn debug_assert_balanced<C: Characteristic>(_: &ModularBigInt<C>) {}
+
 impl<C: Characteristic> FieldElement for ModularBigInt<C> {
     type Char = C;
 }
@@ -151,142 +500,1978 @@ impl<C: Characteristic, T: ArrayLength<ModularBigInt<C>> + PowerOfTwo> RlweRing
     type Coefficient = ModularBigInt<C>;
     type Degree = T;
 
-    fn mul(_a: Element<Self>, _b: Element<Self>) -> Element<Self> {
-        todo!()
+    fn mul(a: Element<Self>, b: Element<Self>) -> Element<Self> {
+        // A zero `a` is already cheap, as its coefficients are skipped.
+        if b.coefficients.iter().all(Zero::is_zero) {
+            return b;
+        }
+        Element::from_unreduced(negacyclic_product(&a, &b))
     }
 }
 
-impl<C, T> From<Vector> for Element<Cyclotomic<T, C>>
+/// Schoolbook multiplication modulo `Xⁿ+1`, without reducing the resulting
+/// coefficients modulo the characteristic.
+fn negacyclic_product<C, T>(
+    a: &Element<Cyclotomic<T, C>>,
+    b: &Element<Cyclotomic<T, C>>,
+) -> Vec<BigInt>
 where
     C: Characteristic,
     T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
 {
-    fn from(p: Vector) -> Self {
-        let degree = T::to_usize();
-        let mut coordinates: Vec<ModularBigInt<C>> = p
-            .coordinates
-            .to_vec()
-            .iter()
-            .map(|x| x.clone().into())
-            .collect();
-        let coefficients = if coordinates.len() <= degree {
-            coordinates.resize(degree, Zero::zero());
-            GenericArray::<ModularBigInt<C>, T>::clone_from_slice(&coordinates)
-        } else {
-            let mut slice: Vec<ModularBigInt<C>> = vec![Zero::zero(); degree];
-            // TODO: Parallelization
-            for i in 0..coordinates.len() {
-                if i / degree % 2 == 0 {
-                    slice[i % degree] += coordinates[i].clone();
-                } else {
-                    slice[i % degree] -= coordinates[i].clone();
-                }
+    let degree = T::to_usize();
+    let mut acc: Vec<BigInt> = vec![Zero::zero(); degree];
+    for (i, x) in a.coefficients.iter().enumerate() {
+        if x.is_zero() {
+            continue;
+        }
+        for (j, y) in b.coefficients.iter().enumerate() {
+            let prod = &x.representant * &y.representant;
+            if i + j < degree {
+                acc[i + j] += prod;
+            } else {
+                acc[i + j - degree] -= prod;
             }
+        }
+    }
+    acc
+}
 
-            GenericArray::<ModularBigInt<C>, T>::clone_from_slice(&slice)
-        };
+/// A running sum of ring elements, kept as plain integers. The coefficients
+/// are reduced only when one of them grows beyond a given number of bits,
+/// which bounds memory while performing far fewer reductions than summing
+/// eagerly. In characteristic zero nothing is ever reduced.
+pub struct Accumulator<R: RlweRing> {
+    acc: Vec<BigInt>,
+    threshold_bits: u64,
+    ring: PhantomData<R>,
+}
 
-        Element::<Cyclotomic<T, C>> { coefficients }
+impl<C, T> Accumulator<Cyclotomic<T, C>>
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+{
+    /// Starts from zero. All coefficients are reduced as soon as one of them
+    /// has more than `threshold_bits` bits. The threshold should exceed the
+    /// bit length of the characteristic, or every addition triggers a
+    /// reduction.
+    pub fn new(threshold_bits: u64) -> Self {
+        Self {
+            acc: vec![Zero::zero(); T::to_usize()],
+            threshold_bits,
+            ring: PhantomData,
+        }
+    }
+
+    /// Adds `x` to the sum.
+    pub fn add(&mut self, x: &Element<Cyclotomic<T, C>>) {
+        for (s, c) in self.acc.iter_mut().zip(x.coefficients.iter()) {
+            *s += &c.representant;
+        }
+        if self.bits() > self.threshold_bits {
+            for s in self.acc.iter_mut() {
+                *s = ModularBigInt::<C>::from(std::mem::take(s)).representant;
+            }
+        }
+    }
+
+    /// Returns the largest bit length among the unreduced coefficients.
+    pub fn bits(&self) -> u64 {
+        self.acc.iter().map(|x| x.bits()).max().unwrap_or(0)
+    }
+
+    /// Reduces the sum into the ring.
+    pub fn finalize(self) -> Element<Cyclotomic<T, C>> {
+        Element::from_unreduced(self.acc)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use num_traits::FromPrimitive;
-    use rand::Rng;
-    use typenum::consts::{U16, U4};
+/// Summary statistics of the balanced representants of an element's
+/// coefficients, as returned by `Element::coefficient_stats`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoeffStats {
+    pub min: BigInt,
+    pub max: BigInt,
+    pub mean: f64,
+    /// The population variance.
+    pub variance: f64,
+    pub zeros: usize,
+}
 
-    use super::*;
-    use crate::characteristic;
-    use crate::traits::CharZero;
+/// The index and sign mapping of the automorphism `X ↦ Xᵏ` on a ring of a
+/// given degree, computed once so that it can be applied repeatedly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AutomorphismMap {
+    /// For each source position `i`, the destination position of its
+    /// coefficient and whether it gets negated.
+    targets: Vec<(usize, bool)>,
+}
 
-    characteristic!(Char7, BigUint::from_u8(7).unwrap());
+impl AutomorphismMap {
+    /// Precomputes `X ↦ Xᵏ` for the given degree `n`. The coefficient of `Xⁱ`
+    /// moves to position `ik mod n`, negated when `ik mod 2n ≥ n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is even, since `X ↦ Xᵏ` is then not an automorphism, or
+    /// if `degree` is not a power of two.
+    pub fn new(k: usize, degree: usize) -> Self {
+        assert!(k % 2 == 1, "automorphism exponent {} must be odd", k);
+        assert!(
+            degree.is_power_of_two(),
+            "degree {} is not a power of two",
+            degree
+        );
+        let k = k % (2 * degree);
+        let targets = (0..degree)
+            .map(|i| {
+                let j = i * k % (2 * degree);
+                (j % degree, j >= degree)
+            })
+            .collect();
+        Self { targets }
+    }
 
-    #[test]
-    fn convert_z() {
-        type R = Cyclotomic<U4, CharZero>;
-        let pairs = [
-            (vec![1, 2, 3, 4, 5, 6, 7, 8], vec![-4, -4, -4, -4]),
-            (vec![0, 1, 2, 3, -1], vec![1, 1, 2, 3]),
-            (vec![0, 1, 2, 3, 0, 0, 0, 0, 1, 1, 1, 1], vec![1, 2, 3, 4]),
-            (vec![42, 42, 42, 42, 42, 42, 42, 42], vec![0, 0, 0, 0]),
-            (
-                vec![0, 1, 2, 3, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1],
-                vec![0, 1, 3, 4],
-            ),
-        ];
-        for pair in pairs {
-            let vec: Vector = pair.0.into();
-            let want: Vector = pair.1.clone().into();
-            let got_e: Element<R> = vec.into();
-            let want_e: Element<R> = want.clone().into();
-            assert_eq!(got_e, want_e);
+    /// Applies the automorphism to `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the map was built for a different degree.
+    pub fn apply<C, T>(
+        &self,
+        x: &Element<Cyclotomic<T, C>>,
+    ) -> Element<Cyclotomic<T, C>>
+    where
+        C: Characteristic,
+        T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+    {
+        assert_eq!(self.targets.len(), T::to_usize());
+        let mut coordinates: Vec<ModularBigInt<C>> =
+            vec![Zero::zero(); self.targets.len()];
+        for (c, (j, negate)) in x.coefficients.iter().zip(self.targets.iter()) {
+            coordinates[*j] = if *negate { -c.clone() } else { c.clone() };
         }
+        let coefficients =
+            GenericArray::<ModularBigInt<C>, T>::clone_from_slice(&coordinates);
+        Element::<Cyclotomic<T, C>> { coefficients }
     }
+}
 
-    #[test]
-    fn convert_zp() {
-        type R = Cyclotomic<U4, Char7>;
+/// Checks that `Xⁿ = -1` in `Cyclotomic<T, C>`, both for the monomial `Xⁿ`
+/// and for the product `Xⁿ⁻¹·X`. A self-test for ring setup.
+pub fn check_negacyclic_invariant<T, C>() -> bool
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+{
+    let n = T::to_usize();
+    let minus_one = -Cyclotomic::<T, C>::one();
+    let x = |k| Element::<Cyclotomic<T, C>>::monomial(One::one(), k);
+    // Element<R> is only PartialEq when C is, so compare differences to zero.
+    let is_minus_one = |e: Element<Cyclotomic<T, C>>| {
+        (e - &minus_one).coefficients.iter().all(Zero::is_zero)
+    };
+    is_minus_one(x(n)) && is_minus_one(Cyclotomic::mul(x(n - 1), x(1)))
+}
 
-        let pairs = [
-            (vec![1, 2, 3, 4, 5, 6, 7, 8], vec![3, 3, 3, 3]),
-            (vec![0, 1, 2, 3, -1], vec![1, 1, 2, 3]),
-            (vec![0, 1, 2, 3, 0, 0, 0, 0, 1, 1, 1, 1], vec![1, 2, 3, 4]),
-            (vec![42, 42, 42, 42, 42, 42, 42, 42], vec![0, 0, 0, 0]),
-            (
-                vec![0, 1, 2, 3, 0, 0, 0, 0, 7, 1, 1, 1, 0, 0, 4],
-                vec![0, 2, -1, 4],
-            ),
-        ];
-        for pair in pairs {
-            let vec: Vector = pair.0.into();
-            let want: Vector = pair.1.clone().into();
-            let got_e: Element<R> = vec.into();
-            let want_e: Element<R> = want.clone().into();
-            assert_eq!(got_e, want_e);
-        }
+/// Returns generators of the automorphism group `(ℤ/2nℤ)*` of the ring of
+/// degree `n`, as exponents `k` of `X ↦ Xᵏ`: `5`, of order `n/2`, generates
+/// the slot rotations, and `2n - 1` the conjugation. Rings of degree 1 and 2
+/// have smaller groups, generated by `2n - 1` alone.
+///
+/// # Panics
+///
+/// Panics if `n` is not a power of two, in particular if it is zero.
+pub fn galois_generators(n: usize) -> Vec<usize> {
+    assert!(n.is_power_of_two(), "degree {} is not a power of two", n);
+    if n <= 2 {
+        vec![2 * n - 1]
+    } else {
+        vec![5, 2 * n - 1]
     }
+}
 
-    #[test]
-    fn double_z() {
-        type R = Cyclotomic<U16, CharZero>;
-        let v: Vector = rand::thread_rng().gen::<[i64; 32]>().to_vec().into();
-        let x: Element<R> = v.into();
-        let sum = x.clone() + &x;
-        let want: Vec<ModularBigInt<CharZero>> = x
-            .coefficients()
-            .iter()
-            .map(|c| c.clone() + c.clone())
-            .collect();
-        assert_eq!(sum.coefficients().as_slice(), want);
+/// Returns the exponent `5ˢ mod 2n` of the automorphism that rotates the
+/// slots by `steps`, where negative steps rotate the other way.
+///
+/// # Panics
+///
+/// Panics if `n` is not a power of two, in particular if it is zero.
+pub fn rotation_to_galois_exponent(steps: i64, n: usize) -> usize {
+    assert!(n.is_power_of_two(), "degree {} is not a power of two", n);
+    let two_n = 2 * n as u64;
+    let order = (n as i64 / 2).max(1);
+    let s = steps.rem_euclid(order) as u64;
+    BigUint::from(5_u32)
+        .modpow(&s.into(), &two_n.into())
+        .to_usize()
+        .unwrap_or_default()
+}
+
+impl<C, T> From<Vector> for Element<Cyclotomic<T, C>>
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+{
+    fn from(p: Vector) -> Self {
+        let degree = T::to_usize();
+        // Each residue class is accumulated as a plain integer, so that every
+        // coefficient is reduced only once, whatever the length of `p`.
+        let mut acc: Vec<BigInt> = vec![Zero::zero(); degree];
+        // TODO: Parallelization
+        for (i, x) in p.coordinates.iter().enumerate() {
+            if i / degree % 2 == 0 {
+                acc[i % degree] += x;
+            } else {
+                acc[i % degree] -= x;
+            }
+        }
+
+        Element::from_unreduced(acc)
     }
+}
 
-    #[test]
-    fn double_char_7() {
-        type R = Cyclotomic<U16, Char7>;
-        let v: Vector = rand::thread_rng().gen::<[i64; 32]>().to_vec().into();
-        let x: Element<R> = v.into();
-        let sum = x.clone() + &x;
-        let want: Vec<ModularBigInt<Char7>> = x
-            .coefficients()
-            .iter()
-            .map(|c| c.clone() + c.clone())
-            .collect();
-        assert_eq!(sum.coefficients().as_slice(), want);
+/// Writes the element as a polynomial in `X` with decreasing exponents and
+/// balanced coefficients, e.g. `3*X^2 - X + 1`. The zero element is `0`.
+impl<C, T> std::fmt::Display for Element<Cyclotomic<T, C>>
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let mut first = true;
+        for (i, c) in self.coefficients.iter().enumerate().rev() {
+            let c = &c.representant;
+            if c.is_zero() {
+                continue;
+            }
+            match (first, c.is_negative()) {
+                (true, true) => write!(f, "-")?,
+                (true, false) => {}
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?,
+            }
+            first = false;
+            let magnitude = c.abs();
+            let monomial = match i {
+                0 => String::new(),
+                1 => "X".to_string(),
+                _ => format!("X^{}", i),
+            };
+            if monomial.is_empty() {
+                write!(f, "{}", magnitude)?;
+            } else if magnitude.is_one() {
+                write!(f, "{}", monomial)?;
+            } else {
+                write!(f, "{}*{}", magnitude, monomial)?;
+            }
+        }
+        if first {
+            write!(f, "0")?;
+        }
+        Ok(())
     }
+}
 
-    #[test]
-    fn hadamard_square() {
-        type R = Cyclotomic<U16, CharZero>;
-        let v: Vector = rand::thread_rng().gen::<[i64; 32]>().to_vec().into();
-        let x: Element<R> = v.clone().into();
-        let y: Element<R> = v.into();
-        let hadamard_square = x.hadamard(&y);
-        let want: Vec<ModularBigInt<CharZero>> = y
-            .coefficients()
-            .iter()
+/// Parses a sum of terms `c*X^k`, where the coefficient `c` may be omitted
+/// (`X^k`), `^k` may be omitted (`c*X`), and a term may be a bare constant.
+/// Whitespace is ignored. Exponents of `n` and above are reduced using
+/// `Xⁿ = -1`, and coefficients modulo the characteristic.
+impl<C, T> FromStr for Element<Cyclotomic<T, C>>
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+{
+    type Err = RlweError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            |reason: &str| RlweError::InvalidPolynomial(reason.to_string());
+        let compact: String =
+            s.chars().filter(|c| !c.is_whitespace()).collect();
+        if compact.is_empty() {
+            return Err(invalid("empty string"));
+        }
+        // Each term starts at a sign, except possibly the first one.
+        let mut terms: Vec<(bool, &str)> = vec![];
+        let mut start = 0;
+        for (i, ch) in compact.char_indices() {
+            if (ch == '+' || ch == '-') && i > 0 {
+                terms.push((false, &compact[start..i]));
+                start = i;
+            }
+        }
+        terms.push((false, &compact[start..]));
+        for term in terms.iter_mut() {
+            if let Some(rest) = term.1.strip_prefix('-') {
+                *term = (true, rest);
+            } else if let Some(rest) = term.1.strip_prefix('+') {
+                *term = (false, rest);
+            }
+        }
+
+        let digits =
+            |x: &str| !x.is_empty() && x.bytes().all(|b| b.is_ascii_digit());
+        let degree = T::to_usize();
+        let two_n = BigUint::from(2 * degree);
+        let mut acc: Vec<BigInt> = vec![Zero::zero(); degree];
+        for (negative, term) in terms {
+            let (coefficient, monomial) = match term.split_once('*') {
+                Some((c, m)) => (c, Some(m)),
+                None if term.starts_with('X') => ("1", Some(term)),
+                None => (term, None),
+            };
+            if !digits(coefficient) {
+                return Err(invalid(&format!(
+                    "bad coefficient in term {:?}",
+                    term
+                )));
+            }
+            // Exponents are reduced modulo 2n, since X²ⁿ = 1, so that
+            // arbitrarily large ones never allocate or overflow.
+            let exponent = match monomial {
+                None => 0,
+                Some("X") => 1,
+                Some(m) => match m.strip_prefix("X^") {
+                    Some(e) if digits(e) => {
+                        let e: BigUint =
+                            e.parse().map_err(|_| invalid(term))?;
+                        (e % &two_n).to_usize().unwrap_or_default()
+                    }
+                    _ => {
+                        return Err(invalid(&format!(
+                            "bad monomial in term {:?}",
+                            term
+                        )))
+                    }
+                },
+            };
+            let c: BigInt = coefficient.parse().map_err(|_| invalid(term))?;
+            // Xⁿ = -1 flips the sign of the upper half.
+            if negative != (exponent >= degree) {
+                acc[exponent % degree] -= c;
+            } else {
+                acc[exponent % degree] += c;
+            }
+        }
+        Ok(Element::from_unreduced(acc))
+    }
+}
+
+/// Wraps already reduced coefficients, which must be exactly `n`.
+impl<C, T> TryFrom<Vec<ModularBigInt<C>>> for Element<Cyclotomic<T, C>>
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+{
+    type Error = RlweError;
+
+    fn try_from(
+        coefficients: Vec<ModularBigInt<C>>,
+    ) -> Result<Self, Self::Error> {
+        if coefficients.len() != T::to_usize() {
+            return Err(RlweError::DimensionMismatch {
+                expected: T::to_usize(),
+                found: coefficients.len(),
+            });
+        }
+        let coefficients =
+            GenericArray::<ModularBigInt<C>, T>::clone_from_slice(
+                &coefficients,
+            );
+        Ok(Element { coefficients })
+    }
+}
+
+impl<C, T, I> ElementSumExt<Cyclotomic<T, C>> for I
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+    I: Iterator<Item = Element<Cyclotomic<T, C>>>,
+{
+    fn sum_ring(self) -> Element<Cyclotomic<T, C>> {
+        Element::sum_elements(self)
+    }
+}
+
+impl<C, T> From<Element<Cyclotomic<T, C>>> for Vector
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+{
+    /// Copies the balanced representants of the coefficients. The resulting
+    /// vector always has length `n`: the vector the element was built from, if
+    /// any, cannot be recovered.
+    fn from(e: Element<Cyclotomic<T, C>>) -> Self {
+        let coordinates: Vec<BigInt> =
+            e.coefficients.into_iter().map(|x| x.representant).collect();
+        Vector { coordinates }
+    }
+}
+
+impl<C, T> Element<Cyclotomic<T, C>>
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+{
+    /// Builds an element from coefficients that have not been reduced yet,
+    /// reducing each of them exactly once.
+    fn from_unreduced(acc: Vec<BigInt>) -> Self {
+        let coordinates: Vec<ModularBigInt<C>> =
+            acc.into_iter().map(|x| x.into()).collect();
+        let coefficients =
+            GenericArray::<ModularBigInt<C>, T>::clone_from_slice(&coordinates);
+        Element::<Cyclotomic<T, C>> { coefficients }
+    }
+
+    /// Computes `Σ αᵢ·aᵢ` for the given pairs `(αᵢ, aᵢ)`. The products are
+    /// accumulated as plain integers, and modular reduction is performed only
+    /// once per coefficient, at the end.
+    pub fn linear_combination(terms: &[(ModularBigInt<C>, &Self)]) -> Self {
+        let mut acc: Vec<BigInt> = vec![Zero::zero(); T::to_usize()];
+        for (alpha, a) in terms {
+            for (s, c) in acc.iter_mut().zip(a.coefficients.iter()) {
+                *s += &alpha.representant * &c.representant;
+            }
+        }
+        Self::from_unreduced(acc)
+    }
+
+    /// Computes `c0 + c1·s`, the core of decryption, reducing each coefficient
+    /// only once instead of once for the product and again for the sum.
+    pub fn decrypt_inner(c0: &Self, c1: &Self, s: &Self) -> Self {
+        let mut acc = negacyclic_product(c1, s);
+        for (x, c) in acc.iter_mut().zip(c0.coefficients.iter()) {
+            *x += &c.representant;
+        }
+        Self::from_unreduced(acc)
+    }
+
+    /// Sums the elements yielded by `iter`, accumulating plain integers and
+    /// reducing each coefficient only once at the end. The empty sum is zero.
+    pub fn sum_elements<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let mut acc: Vec<BigInt> = vec![Zero::zero(); T::to_usize()];
+        for x in iter {
+            for (s, c) in acc.iter_mut().zip(x.coefficients.iter()) {
+                *s += &c.representant;
+            }
+        }
+        Self::from_unreduced(acc)
+    }
+
+    /// Returns the `n×n` skew-circulant matrix `M` of multiplication by
+    /// `self`: column `j` holds the coefficients of `self·Xʲ`, so `M·b` is the
+    /// coefficient vector of `self·b`.
+    pub fn to_multiplication_matrix(&self) -> Vec<Vec<ModularBigInt<C>>> {
+        let degree = T::to_usize();
+        let a = &self.coefficients;
+        (0..degree)
+            .map(|i| {
+                (0..degree)
+                    .map(|j| {
+                        if i >= j {
+                            a[i - j].clone()
+                        } else {
+                            -a[degree + i - j].clone()
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Multiplies the given elements along a balanced binary tree rather than
+    /// a left fold. The result is the same, but in characteristic zero most
+    /// multiplications then involve operands with small coefficients, instead
+    /// of an ever-growing accumulator. The empty product is one.
+    pub fn product(elements: &[Self]) -> Self {
+        match elements.len() {
+            0 => Vector::from(vec![1]).into(),
+            1 => elements[0].clone(),
+            len => {
+                let (left, right) = elements.split_at(len / 2);
+                Cyclotomic::mul(Self::product(left), Self::product(right))
+            }
+        }
+    }
+
+    /// Computes statistics over the balanced representants of the
+    /// coefficients, which is handy when debugging samplers or noise growth.
+    pub fn coefficient_stats(&self) -> CoeffStats {
+        let reps = self.coefficients.iter().map(|c| &c.representant);
+        let n = BigInt::from(T::to_usize());
+        let sum: BigInt = reps.clone().sum();
+        let sum_sq: BigInt = reps.clone().map(|x| x * x).sum();
+        let n_sq = &n * &n;
+        let to_f64 = |x: BigInt| x.to_f64().unwrap_or(f64::NAN);
+        CoeffStats {
+            min: reps.clone().min().cloned().unwrap_or_default(),
+            max: reps.clone().max().cloned().unwrap_or_default(),
+            mean: to_f64(sum.clone()) / to_f64(n.clone()),
+            variance: to_f64(&n * sum_sq - &sum * &sum) / to_f64(n_sq),
+            zeros: reps.filter(|x| x.is_zero()).count(),
+        }
+    }
+
+    /// Encodes a bit vector one bit per coefficient, mapping `true` to 1 and
+    /// `false` to 0. Shorter inputs are zero-padded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than `n` bits, which would not round-trip
+    /// through `to_bits`.
+    pub fn from_bits(bits: &[bool]) -> Self {
+        assert!(
+            bits.len() <= T::to_usize(),
+            "{} bits do not fit in {} coefficients",
+            bits.len(),
+            T::to_usize()
+        );
+        let coordinates: Vec<i64> = bits.iter().map(|b| *b as i64).collect();
+        Vector::from(coordinates).into()
+    }
+
+    /// Decodes one bit per coefficient: a coefficient is `true` when the
+    /// absolute value of its balanced representant exceeds `threshold`. For
+    /// bits scaled by `Δ` and perturbed by noise, use `threshold = Δ/2`.
+    pub fn to_bits(&self, threshold: &BigInt) -> Vec<bool> {
+        self.coefficients
+            .iter()
+            .map(|c| c.representant.magnitude() > threshold.magnitude())
+            .collect()
+    }
+
+    /// Returns the largest absolute value among the balanced representants.
+    pub fn norm_infinity(&self) -> BigUint {
+        self.coefficients
+            .iter()
+            .map(|c| c.representant.magnitude().clone())
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns the infinity norm of the canonical embedding, that is, the
+    /// largest `|a(ζ)|` over the complex primitive `2n`-th roots of unity `ζ`.
+    pub fn canonical_norm(&self) -> f64 {
+        let degree = T::to_usize();
+        let reps: Vec<f64> = self
+            .coefficients
+            .iter()
+            .map(|c| c.representant.to_f64().unwrap_or(f64::NAN))
+            .collect();
+        (0..degree)
+            .map(|j| {
+                let angle =
+                    std::f64::consts::PI * (2 * j + 1) as f64 / degree as f64;
+                let (mut re, mut im) = (0.0, 0.0);
+                for (i, c) in reps.iter().enumerate() {
+                    re += c * (angle * i as f64).cos();
+                    im += c * (angle * i as f64).sin();
+                }
+                re.hypot(im)
+            })
+            .fold(0.0, f64::max)
+    }
+
+    /// Returns the largest bit length among the absolute values of the
+    /// balanced representants. In characteristic zero, folding a long `Vector`
+    /// can produce coefficients that no longer fit in an `i64`; this detects
+    /// it. A bit length of at most 63 guarantees that every coefficient fits;
+    /// at 64 only `i64::MIN` still does.
+    pub fn bit_length(&self) -> usize {
+        self.coefficients
+            .iter()
+            .map(|c| c.representant.bits() as usize)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns the balanced representants of the coefficients, each reduced
+    /// again into `(-m/2, m/2]`. Useful to inspect residues, e.g. of the noise
+    /// modulo the plaintext modulus, without changing the characteristic.
+    pub fn coefficients_mod(&self, m: &BigUint) -> Vec<BigInt> {
+        self.coefficients
+            .iter()
+            .map(|c| balanced_rem(&c.representant, m))
+            .collect()
+    }
+
+    /// Returns each balanced representant divided by `scale`, e.g. to inspect
+    /// the approximate values encoded by a CKKS plaintext in coefficient
+    /// form. No canonical embedding decode is performed.
+    pub fn coefficients_as_f64(&self, scale: f64) -> Vec<f64> {
+        self.coefficients
+            .iter()
+            .map(|c| c.representant.to_f64().unwrap_or(f64::NAN) / scale)
+            .collect()
+    }
+
+    /// Divides each balanced representant by `factor`, rounding to the nearest
+    /// integer (halves away from zero), and stays in the same ring.
+    ///
+    /// For an element encoding `m` at scale `Δ`, i.e. with coefficients close
+    /// to `Δ·m`, the result encodes `m` at scale `Δ/factor`, and must then be
+    /// decoded at that scale. Unlike modulus switching, the modulus is left
+    /// unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is zero.
+    pub fn rescale_by(&self, factor: &BigInt) -> Self {
+        assert!(!factor.is_zero(), "cannot rescale by zero");
+        let f = factor.abs();
+        let coordinates: Vec<BigInt> = self
+            .coefficients
+            .iter()
+            .map(|c| {
+                let x = &c.representant;
+                let twice: BigInt = x.abs() * 2 + &f;
+                let q = twice.div_floor(&(&f * 2));
+                let q = if x.is_negative() { -q } else { q };
+                if factor.is_negative() {
+                    -q
+                } else {
+                    q
+                }
+            })
+            .collect();
+        Self::from_unreduced(coordinates)
+    }
+
+    /// Returns the infinity norm of `self - other`.
+    pub fn distance_infinity(&self, other: &Self) -> BigUint {
+        (self.clone() - other).norm_infinity()
+    }
+
+    /// Returns the canonical norm of `self - other`.
+    pub fn distance_canonical(&self, other: &Self) -> f64 {
+        (self.clone() - other).canonical_norm()
+    }
+
+    /// Returns true if every coefficient of `self - other` has a balanced
+    /// representant of absolute value at most `tolerance`. A negative
+    /// tolerance is never met.
+    pub fn approx_eq(&self, other: &Self, tolerance: &BigInt) -> bool {
+        !tolerance.is_negative()
+            && BigInt::from(self.distance_infinity(other)) <= *tolerance
+    }
+
+    /// Negates every coefficient in place.
+    pub fn negate_mut(&mut self) {
+        self.coefficients.iter_mut().for_each(|c| c.negate_mut());
+    }
+
+    /// Returns the element whose coefficients are 1 where the balanced
+    /// representant of `self` exceeds `threshold`, and 0 elsewhere. This is a
+    /// plaintext helper, not a homomorphic operation.
+    pub fn threshold_mask(&self, threshold: &BigInt) -> Self {
+        let coordinates: Vec<ModularBigInt<C>> = self
+            .coefficients
+            .iter()
+            .map(|c| {
+                if c.representant > *threshold {
+                    One::one()
+                } else {
+                    Zero::zero()
+                }
+            })
+            .collect();
+        let coefficients =
+            GenericArray::<ModularBigInt<C>, T>::clone_from_slice(&coordinates);
+        Element::<Cyclotomic<T, C>> { coefficients }
+    }
+
+    /// Applies the ring automorphism `X ↦ Xᵏ`, for `k` odd. The coefficient of
+    /// `Xⁱ` moves to position `ik mod n`, negated when `ik mod 2n ≥ n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is even, since `X ↦ Xᵏ` is then not an automorphism.
+    pub fn automorphism(&self, k: usize) -> Self {
+        AutomorphismMap::new(k, T::to_usize()).apply(self)
+    }
+
+    /// Applies the `power`-th iterate of the Frobenius map `X ↦ Xᵖ`, where `p`
+    /// is the characteristic. Since the coefficients lie in `𝔽_p`, this is
+    /// also `a ↦ a^(p^power)`.
+    ///
+    /// On slots, i.e. on the evaluations at the roots `ψ²ʲ⁺¹` of `Xⁿ+1`, it
+    /// acts by moving the value at `ψᵉ` to `ψ^(e·p^-power)`: the slots are
+    /// permuted within each orbit of multiplication by `p` modulo `2n`. When
+    /// `p ≡ 1 mod 2n`, every slot is fixed and the map is the identity.
+    ///
+    /// # Panics
+    ///
+    /// Panics in characteristic zero, and in even characteristic, where
+    /// `X ↦ Xᵖ` is not an automorphism of the ring.
+    pub fn frobenius(&self, power: usize) -> Self {
+        let p = C::to_biguint();
+        assert!(
+            !p.is_zero(),
+            "the Frobenius map needs a positive characteristic"
+        );
+        assert!(
+            p.bit(0),
+            "the Frobenius map needs an odd characteristic, got {}",
+            p
+        );
+        let two_n = BigUint::from(2 * T::to_usize());
+        let k = p.modpow(&BigUint::from(power), &two_n);
+        self.automorphism(k.to_usize().unwrap_or_default())
+    }
+
+    /// Returns the monomial `c·Xᵏ`. For `k ≥ n` the exponent is reduced using
+    /// `Xⁿ = -1`, so `c` lands at position `k mod n`, with its sign flipped
+    /// whenever `k / n` is odd.
+    pub fn monomial(c: ModularBigInt<C>, k: usize) -> Self {
+        let degree = T::to_usize();
+        let mut coordinates: Vec<ModularBigInt<C>> = vec![Zero::zero(); degree];
+        coordinates[k % degree] = if (k / degree).is_multiple_of(2) {
+            c
+        } else {
+            -c
+        };
+        let coefficients =
+            GenericArray::<ModularBigInt<C>, T>::clone_from_slice(&coordinates);
+        Element::<Cyclotomic<T, C>> { coefficients }
+    }
+
+    /// Cyclically rotates the coefficient array, moving the coefficient of
+    /// `Xⁱ` to position `(i + shift) mod n` with no change of sign. This is
+    /// a data-structure operation, not a ring operation: multiplying by
+    /// `X^shift` instead negates the coefficients that wrap around.
+    pub fn rotate_coefficients(&self, shift: i64) -> Self {
+        let degree = T::to_usize();
+        let shift = shift.rem_euclid(degree as i64) as usize;
+        let mut coefficients = self.coefficients.clone();
+        coefficients.rotate_right(shift);
+        Element::<Cyclotomic<T, C>> { coefficients }
+    }
+
+    /// Returns the formal derivative `Σ i·aᵢ·Xⁱ⁻¹` of the representative of
+    /// degree less than `n`, with coefficients reduced modulo the
+    /// characteristic. The result has degree less than `n - 1`, so nothing
+    /// wraps around. Because `Xⁿ+1` has a nonzero derivative, this is not
+    /// well defined on the quotient ring: it depends on the representative.
+    pub fn derivative(&self) -> Self {
+        let mut coordinates: Vec<BigInt> = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| &c.representant * i)
+            .collect();
+        coordinates.push(Zero::zero());
+        Self::from_unreduced(coordinates)
+    }
+
+    /// Embeds the element into a ring of larger degree by zero-padding its
+    /// coefficients. The represented polynomial is preserved, but note that
+    /// this is not a ring homomorphism, since `Xⁿ ≠ -1` in the larger ring.
+    pub fn extend<T2>(&self) -> Element<Cyclotomic<T2, C>>
+    where
+        T2: ArrayLength<ModularBigInt<C>>
+            + PowerOfTwo
+            + IsGreaterOrEqual<T, Output = True>,
+    {
+        let mut coordinates = self.coefficients.to_vec();
+        coordinates.resize(T2::to_usize(), Zero::zero());
+        let coefficients =
+            GenericArray::<ModularBigInt<C>, T2>::clone_from_slice(
+                &coordinates,
+            );
+        Element::<Cyclotomic<T2, C>> { coefficients }
+    }
+
+    /// Projects the element into a ring of smaller degree `n₂`, reducing it
+    /// modulo `X^n₂+1` as `From<Vector>` does.
+    pub fn project<T2>(&self) -> Element<Cyclotomic<T2, C>>
+    where
+        T: IsGreaterOrEqual<T2, Output = True>,
+        T2: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+    {
+        Vector::from(self.clone()).into()
+    }
+
+    /// Projects the element into a ring of smaller degree `n₂` without losing
+    /// information: fails with `RlweError::LossyProjection` if any coefficient
+    /// of degree `n₂` or higher is nonzero.
+    pub fn try_project<T2>(
+        &self,
+    ) -> Result<Element<Cyclotomic<T2, C>>, RlweError>
+    where
+        T: IsGreaterOrEqual<T2, Output = True>,
+        T2: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+    {
+        if self.coefficients[T2::to_usize()..]
+            .iter()
+            .any(|c| !c.is_zero())
+        {
+            return Err(RlweError::LossyProjection);
+        }
+        Ok(self.project())
+    }
+
+    /// Returns `Some((c, k))` if the element is the monomial `c·Xᵏ` with `c`
+    /// nonzero, and `None` otherwise (in particular, for the zero element).
+    pub fn as_monomial(&self) -> Option<(ModularBigInt<C>, usize)> {
+        let mut nonzero = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| !c.is_zero());
+        match (nonzero.next(), nonzero.next()) {
+            (Some((k, c)), None) => Some((c.clone(), k)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_traits::FromPrimitive;
+    use rand::Rng;
+    use typenum::consts::{U16, U4};
+
+    use super::*;
+    use crate::characteristic;
+    use crate::traits::CharZero;
+
+    characteristic!(Char7, BigUint::from_u8(7).unwrap());
+    characteristic!(Char257, BigUint::from_u16(257).unwrap());
+    characteristic!(Char7681, BigUint::from_u16(7681).unwrap());
+    characteristic!(Char2p61m1, (BigUint::one() << 61) - 1_u32);
+
+    #[test]
+    fn convert_z() {
+        type R = Cyclotomic<U4, CharZero>;
+        let pairs = [
+            (vec![1, 2, 3, 4, 5, 6, 7, 8], vec![-4, -4, -4, -4]),
+            (vec![0, 1, 2, 3, -1], vec![1, 1, 2, 3]),
+            (vec![0, 1, 2, 3, 0, 0, 0, 0, 1, 1, 1, 1], vec![1, 2, 3, 4]),
+            (vec![42, 42, 42, 42, 42, 42, 42, 42], vec![0, 0, 0, 0]),
+            (
+                vec![0, 1, 2, 3, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1],
+                vec![0, 1, 3, 4],
+            ),
+        ];
+        for pair in pairs {
+            let vec: Vector = pair.0.into();
+            let want: Vector = pair.1.clone().into();
+            let got_e: Element<R> = vec.into();
+            let want_e: Element<R> = want.clone().into();
+            assert_eq!(got_e, want_e);
+        }
+    }
+
+    #[test]
+    fn convert_zp() {
+        type R = Cyclotomic<U4, Char7>;
+
+        let pairs = [
+            (vec![1, 2, 3, 4, 5, 6, 7, 8], vec![3, 3, 3, 3]),
+            (vec![0, 1, 2, 3, -1], vec![1, 1, 2, 3]),
+            (vec![0, 1, 2, 3, 0, 0, 0, 0, 1, 1, 1, 1], vec![1, 2, 3, 4]),
+            (vec![42, 42, 42, 42, 42, 42, 42, 42], vec![0, 0, 0, 0]),
+            (
+                vec![0, 1, 2, 3, 0, 0, 0, 0, 7, 1, 1, 1, 0, 0, 4],
+                vec![0, 2, -1, 4],
+            ),
+        ];
+        for pair in pairs {
+            let vec: Vector = pair.0.into();
+            let want: Vector = pair.1.clone().into();
+            let got_e: Element<R> = vec.into();
+            let want_e: Element<R> = want.clone().into();
+            assert_eq!(got_e, want_e);
+        }
+    }
+
+    #[test]
+    fn mul_z() {
+        type R = Cyclotomic<U4, CharZero>;
+        // (1 + 2X)(3 + X³) = 3 + 6X + X³ + 2X⁴ = 1 + 6X + X³
+        let x: Element<R> = Vector::from(vec![1, 2]).into();
+        let y: Element<R> = Vector::from(vec![3, 0, 0, 1]).into();
+        let want: Element<R> = Vector::from(vec![1, 6, 0, 1]).into();
+        assert_eq!(R::mul(x, y), want);
+    }
+
+    #[test]
+    fn mul_matches_long_vector_reduction() {
+        type R = Cyclotomic<U16, Char7>;
+        let mut rng = rand::thread_rng();
+        let a: Vec<i64> = (0..16).map(|_| rng.gen_range(-100..100)).collect();
+        let b: Vec<i64> = (0..16).map(|_| rng.gen_range(-100..100)).collect();
+        let mut product = vec![0; 32];
+        for i in 0..16 {
+            for j in 0..16 {
+                product[i + j] += a[i] * b[j];
+            }
+        }
+        let x: Element<R> = Vector::from(a).into();
+        let y: Element<R> = Vector::from(b).into();
+        let want: Element<R> = Vector::from(product).into();
+        assert_eq!(R::mul(x, y), want);
+    }
+
+    #[test]
+    fn zero_and_one() {
+        type R = Cyclotomic<U16, Char7681>;
+        let mut rng = rand::thread_rng();
+        let a: Vec<i64> = (0..16).map(|_| rng.gen_range(-5000..5000)).collect();
+        let x: Element<R> = Vector::from(a).into();
+        assert_eq!(R::mul(x.clone(), R::one()), x);
+        assert_eq!(R::mul(R::one(), x.clone()), x);
+        assert_eq!(R::mul(x.clone(), R::zero()), R::zero());
+        assert_eq!(x.clone() + &R::zero(), x);
+        assert_eq!(R::one(), Element::monomial(One::one(), 0));
+    }
+
+    #[test]
+    fn mul_pow2() {
+        fn check<C: Characteristic + Debug + PartialEq>(values: &[i64]) {
+            let two: ModularBigInt<C> = BigInt::from(2).into();
+            for v in values {
+                let x: ModularBigInt<C> = BigInt::from(*v).into();
+                for k in [0, 1, 2, 5, 13, 31, 64, 100, 200, 1000] {
+                    assert_eq!(
+                        x.mul_pow2(k),
+                        x.clone() * two.pow(k.into()),
+                        "{:?} · 2^{}",
+                        x,
+                        k
+                    );
+                }
+            }
+        }
+        check::<Char7>(&[0, 1, -1, 3, -3, 2]);
+        check::<Char257>(&[0, 1, -128, 128, 77, -5]);
+        check::<Char7681>(&[0, 1, -3840, 3840, 1234]);
+        check::<Char2p61m1>(&[0, 1, -1, i64::MAX, i64::MIN, 1 << 60]);
+        assert_eq!(special_form(&BigUint::from(7_u32)), Some((3, 1.into())));
+        assert_eq!(
+            special_form(&BigUint::from(257_u32)),
+            Some((8, (-1).into()))
+        );
+        assert_eq!(special_form(&BigUint::from(7681_u32)), None);
+
+        let x: ModularBigInt<CharZero> = BigInt::from(-3).into();
+        assert_eq!(x.mul_pow2(70), BigInt::from(-3 * (1_i128 << 70)).into());
+    }
+
+    #[test]
+    fn constant_term() {
+        type R = Cyclotomic<U4, Char7681>;
+        let x: Element<R> = Vector::from(vec![-17, 5, 0, 3]).into();
+        assert_eq!(x.constant_term(), BigInt::from(-17).into());
+        assert_eq!(x.without_constant(), Vector::from(vec![0, 5, 0, 3]).into());
+        assert_eq!(
+            Element::constant(x.constant_term()) + &x.without_constant(),
+            x
+        );
+        assert_eq!(Element::<R>::constant(One::one()), R::one());
+    }
+
+    #[test]
+    fn add_assign_integer() {
+        type R = Cyclotomic<U4, Char7>;
+        let mut x: Element<R> = Vector::from(vec![2, 1, -3, 0]).into();
+        x += &BigInt::from(3);
+        assert_eq!(x, Vector::from(vec![-2, 1, -3, 0]).into());
+        x += &BigInt::from(-700);
+        assert_eq!(x, Vector::from(vec![-2, 1, -3, 0]).into());
+        x += &BigInt::from(1);
+        assert_eq!(x, Vector::from(vec![-1, 1, -3, 0]).into());
+    }
+
+    #[test]
+    fn sqrt() {
+        fn check<C: Characteristic + Debug + PartialEq>(values: &[i64]) {
+            for v in values {
+                let x: ModularBigInt<C> = BigInt::from(*v).into();
+                let square = x.clone() * x.clone();
+                let root = square.sqrt().unwrap();
+                assert!(root == x || root == -x.clone(), "sqrt({:?})", square);
+            }
+        }
+        let mut rng = rand::thread_rng();
+        let values: Vec<i64> = (0..50).map(|_| rng.gen()).collect();
+        // 7 and 2⁶¹ - 1 are 3 mod 4; 7681 - 1 = 15·2⁹ exercises Tonelli–Shanks.
+        check::<Char7>(&values);
+        check::<Char257>(&values);
+        check::<Char7681>(&values);
+        check::<Char2p61m1>(&values);
+
+        type M = ModularBigInt<Char7681>;
+        let mut is_square = vec![false; 7681];
+        for i in 0..7681_usize {
+            is_square[i * i % 7681] = true;
+        }
+        for (i, square) in is_square.into_iter().enumerate().step_by(13) {
+            let x = M::from(BigInt::from(i));
+            assert_eq!(x.sqrt().is_some(), square, "{}", i);
+        }
+        // 3 is not a square modulo 7.
+        assert_eq!(ModularBigInt::<Char7>::from(BigInt::from(3)).sqrt(), None);
+
+        // Modulo 65 Tonelli–Shanks used to loop forever on 8, and modulo 85
+        // and 10 to underflow on 16 and 9.
+        fn terminates<C: Characteristic + Debug + PartialEq>(modulus: i64) {
+            for v in 0..modulus {
+                let x: ModularBigInt<C> = BigInt::from(v).into();
+                if let Some(root) = x.sqrt() {
+                    assert_eq!(root.clone() * root, x);
+                }
+            }
+        }
+        characteristic!(Char65, BigUint::from_u8(65).unwrap());
+        characteristic!(Char85, BigUint::from_u8(85).unwrap());
+        terminates::<Char65>(65);
+        terminates::<Char85>(85);
+        characteristic!(Char10, BigUint::from_u8(10).unwrap());
+        terminates::<Char10>(10);
+
+        let z = |v: i64| ModularBigInt::<CharZero>::from(BigInt::from(v));
+        assert_eq!(z(144).sqrt(), Some(z(12)));
+        assert_eq!(z(145).sqrt(), None);
+        assert_eq!(z(-4).sqrt(), None);
+    }
+
+    #[test]
+    fn pow_and_inverse() {
+        type M = ModularBigInt<Char7>;
+        let three: M = BigInt::from(3).into();
+        assert_eq!(three.pow(0), M::one());
+        assert_eq!(three.pow(6), M::one());
+        assert_eq!(three.pow(2), BigInt::from(2).into());
+        for i in 1..7 {
+            let x: M = BigInt::from(i).into();
+            assert_eq!(x.clone() * x.inverse().unwrap(), M::one());
+        }
+        assert_eq!(M::zero().inverse(), None);
+
+        type Z = ModularBigInt<CharZero>;
+        let two: Z = BigInt::from(2).into();
+        assert_eq!(two.pow(70), BigInt::from(2).pow(70).into());
+        assert_eq!(two.inverse(), None);
+        assert_eq!((-Z::one()).inverse(), Some(-Z::one()));
+    }
+
+    #[test]
+    fn extend_and_project() {
+        let v: Vector = vec![1, -2, 3, 0].into();
+        let x: Element<Cyclotomic<U4, Char7>> = v.clone().into();
+        let y: Element<Cyclotomic<U16, Char7>> = x.extend();
+        let mut padded = v.coordinates().clone();
+        padded.resize(16, Zero::zero());
+        assert_eq!(Vector::from(y.clone()), padded.into());
+        assert_eq!(y.try_project::<U4>(), Ok(x.clone()));
+        assert_eq!(y.project::<U4>(), x);
+
+        let z: Element<Cyclotomic<U16, Char7>> =
+            Vector::from(vec![1, 0, 0, 0, 0, 2]).into();
+        assert_eq!(z.try_project::<U4>(), Err(RlweError::LossyProjection));
+        assert_eq!(
+            z.project::<U4>(),
+            Vector::from(vec![1, 0, 0, 0, 0, 2]).into()
+        );
+    }
+
+    #[test]
+    fn decrypt_inner() {
+        type R = Cyclotomic<U16, Char7681>;
+        let mut rng = rand::thread_rng();
+        let mut random = || -> Element<R> {
+            Vector::from(rng.gen::<[i64; 16]>().to_vec()).into()
+        };
+        let (c0, c1, s) = (random(), random(), random());
+        assert_eq!(
+            Element::decrypt_inner(&c0, &c1, &s),
+            c0 + &R::mul(c1.clone(), s.clone())
+        );
+    }
+
+    #[test]
+    fn sum_ring() {
+        type R = Cyclotomic<U16, Char7>;
+        let mut rng = rand::thread_rng();
+        let elements: Vec<Element<R>> = (0..50)
+            .map(|_| Vector::from(rng.gen::<[i64; 16]>().to_vec()).into())
+            .collect();
+        let zero: Element<R> = Vector::from(vec![0]).into();
+        let fold = elements.iter().fold(zero.clone(), |acc, x| acc + x);
+        assert_eq!(elements.clone().into_iter().sum_ring(), fold);
+        assert_eq!(Element::sum_elements(elements.into_iter()), fold);
+        assert_eq!(Vec::<Element<R>>::new().into_iter().sum_ring(), zero);
+    }
+
+    #[test]
+    fn multiplication_matrix() {
+        type R = Cyclotomic<U16, Char7681>;
+        let mut rng = rand::thread_rng();
+        let a: Vec<i64> = (0..16).map(|_| rng.gen_range(-4000..4000)).collect();
+        let b: Vec<i64> = (0..16).map(|_| rng.gen_range(-4000..4000)).collect();
+        let x: Element<R> = Vector::from(a).into();
+        let y: Element<R> = Vector::from(b).into();
+        let product: Vec<ModularBigInt<Char7681>> = x
+            .to_multiplication_matrix()
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .zip(y.coefficients.iter())
+                    .fold(Zero::zero(), |acc, (m, c)| acc + m * c.clone())
+            })
+            .collect();
+        assert_eq!(product.as_slice(), R::mul(x, y).coefficients().as_slice());
+    }
+
+    #[cfg(feature = "reduction-count")]
+    #[test]
+    fn lazy_sum_reduces_less() {
+        type R = Cyclotomic<U16, Char7681>;
+        let mut rng = rand::thread_rng();
+        let elements: Vec<Element<R>> = (0..10)
+            .map(|_| Vector::from(rng.gen::<[i64; 16]>().to_vec()).into())
+            .collect();
+
+        // Reducing after each of the nine additions divides every time.
+        reset_reduction_count();
+        let eager = elements[1..].iter().fold(elements[0].clone(), |acc, x| {
+            let sums = acc
+                .coefficients
+                .iter()
+                .zip(x.coefficients.iter())
+                .map(|(a, b)| &a.representant + &b.representant)
+                .collect();
+            Element::from_unreduced(sums)
+        });
+        let eager_count = reduction_count();
+        reset_reduction_count();
+        let lazy = elements.clone().into_iter().sum_ring();
+        let lazy_count = reduction_count();
+        // `Add` only ever corrects by ±q, which is no division.
+        reset_reduction_count();
+        let added = elements[1..]
+            .iter()
+            .fold(elements[0].clone(), |acc, x| acc + x);
+        let added_count = reduction_count();
+
+        assert_eq!(eager, lazy);
+        assert_eq!(added, lazy);
+        assert_eq!(eager_count, 9 * 16);
+        assert_eq!(lazy_count, 16);
+        assert_eq!(added_count, 0);
+    }
+
+    #[test]
+    fn accumulator() {
+        type R = Cyclotomic<U16, Char7681>;
+        let mut rng = rand::thread_rng();
+        let mut acc = Accumulator::<R>::new(20);
+        let mut eager = R::zero();
+        for _ in 0..1000 {
+            let v: Vec<i64> = (0..16).map(|_| rng.gen()).collect();
+            let x: Element<R> = Vector::from(v).into();
+            acc.add(&x);
+            // Crossing the threshold within an add is reduced right away.
+            assert!(acc.bits() <= 20);
+            eager = eager + &x;
+        }
+        assert_eq!(acc.finalize(), eager);
+
+        type S = Cyclotomic<U4, CharZero>;
+        let mut exact = Accumulator::<S>::new(8);
+        let x: Element<S> = Vector::from(vec![1000, -1000]).into();
+        exact.add(&x);
+        exact.add(&x);
+        assert_eq!(exact.bits(), 11);
+        assert_eq!(exact.finalize(), Vector::from(vec![2000, -2000]).into());
+    }
+
+    #[test]
+    fn product_matches_left_fold() {
+        fn check<C: Characteristic + Debug + PartialEq>() {
+            type T = U16;
+            let mut rng = rand::thread_rng();
+            let elements: Vec<Element<Cyclotomic<T, C>>> = (0..7)
+                .map(|_| {
+                    let v: Vec<i64> =
+                        (0..16).map(|_| rng.gen_range(-9..10)).collect();
+                    Vector::from(v).into()
+                })
+                .collect();
+            let fold =
+                elements.iter().skip(1).fold(elements[0].clone(), |acc, x| {
+                    Cyclotomic::mul(acc, x.clone())
+                });
+            assert_eq!(Element::product(&elements), fold);
+            assert_eq!(Element::product(&elements[..1]), elements[0]);
+        }
+        check::<Char7>();
+        check::<CharZero>();
+
+        type R = Cyclotomic<U4, Char7>;
+        assert_eq!(Element::<R>::product(&[]), Vector::from(vec![1]).into());
+    }
+
+    #[test]
+    fn coefficient_stats() {
+        type R = Cyclotomic<U16, Char7>;
+        let mut rng = rand::thread_rng();
+        let h = 5;
+        let mut v = vec![0_i64; 16];
+        for c in v.iter_mut().take(h) {
+            *c = if rng.gen() { 1 } else { -1 };
+        }
+        let x: Element<R> = Vector::from(v).into();
+        let stats = x.coefficient_stats();
+        assert_eq!(stats.zeros, 16 - h);
+        assert!(stats.min >= BigInt::from(-1));
+        assert!(stats.max <= BigInt::from(1));
+
+        let y: Element<R> = Vector::from(vec![3, -3, 1, -1]).into();
+        let stats = y.coefficient_stats();
+        assert_eq!(stats.min, BigInt::from(-3));
+        assert_eq!(stats.max, BigInt::from(3));
+        assert_eq!(stats.mean, 0.0);
+        assert_eq!(stats.variance, 20.0 / 16.0);
+        assert_eq!(stats.zeros, 12);
+    }
+
+    #[test]
+    fn vector_from_slices() {
+        assert_eq!(
+            Vector::from(&[1_i64, 2, 3][..]),
+            Vector::from(vec![1, 2, 3])
+        );
+        let big: Vec<BigInt> = vec![BigInt::from(-1), BigInt::from(1) << 70];
+        assert_eq!(Vector::from(&big[..]), Vector::from(big.clone()));
+    }
+
+    #[test]
+    fn bits_round_trip() {
+        type R = Cyclotomic<U16, Char7681>;
+        let mut rng = rand::thread_rng();
+        let bits: Vec<bool> = (0..11).map(|_| rng.gen()).collect();
+        let delta: ModularBigInt<Char7681> = BigInt::from(1000).into();
+        let noise: Vec<i64> =
+            (0..16).map(|_| rng.gen_range(-10..=10)).collect();
+        let x = Element::<R>::from_bits(&bits).scalar_mul(&delta)
+            + &Vector::from(noise).into();
+        let mut want = bits.clone();
+        want.resize(16, false);
+        assert_eq!(x.to_bits(&BigInt::from(500)), want);
+
+        let y = Element::<R>::from_bits(&bits);
+        assert_eq!(y.to_bits(&BigInt::zero()), want);
+    }
+
+    #[test]
+    #[should_panic(expected = "17 bits do not fit in 16 coefficients")]
+    fn too_many_bits() {
+        Element::<Cyclotomic<U16, Char7681>>::from_bits(&[true; 17]);
+    }
+
+    #[test]
+    fn distances() {
+        type R = Cyclotomic<U4, Char7>;
+        let x: Element<R> = Vector::from(vec![3, -1, 0, 2]).into();
+        let y: Element<R> = Vector::from(vec![1, 1, 1, 1]).into();
+        assert!(x.distance_infinity(&x).is_zero());
+        assert_eq!(x.distance_canonical(&x), 0.0);
+
+        let diff = x.clone() - &y;
+        assert_eq!(diff, Vector::from(vec![2, -2, -1, 1]).into());
+        assert_eq!(x.distance_infinity(&y), BigUint::from(2_u32));
+        assert_eq!(x.distance_canonical(&y), diff.canonical_norm());
+
+        // Every root of unity has modulus one, so |3X²| = 3 everywhere.
+        let z: Element<R> = Vector::from(vec![0, 0, 3]).into();
+        let zero: Element<R> = Vector::from(vec![0]).into();
+        assert!((z.distance_canonical(&zero) - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn zero_element() {
+        type R = Cyclotomic<U16, Char7681>;
+        let zero = R::zero();
+        assert!(zero.norm_infinity().is_zero());
+        assert_eq!(zero.canonical_norm(), 0.0);
+        assert_eq!(zero.bit_length(), 0);
+        assert_eq!(zero.as_monomial(), None);
+
+        let x: Element<R> = Vector::from(vec![5, -3, 0, 7]).into();
+        assert_eq!(R::mul(x.clone(), zero.clone()), zero);
+        assert_eq!(R::mul(zero.clone(), x.clone()), zero);
+        assert_eq!(R::mul(zero.clone(), zero.clone()), zero);
+        assert_eq!(x.clone() - &x, zero);
+        assert_eq!(Element::product(&[x.clone(), zero.clone(), x]), zero);
+    }
+
+    #[test]
+    fn approx_eq() {
+        type R = Cyclotomic<U4, Char7681>;
+        let x: Element<R> = Vector::from(vec![3840, -3840, 0, 17]).into();
+        let y: Element<R> = Vector::from(vec![-3840, 3840, 1, 16]).into();
+        assert!(x.approx_eq(&y, &BigInt::one()));
+        assert!(!x.approx_eq(&y, &BigInt::zero()));
+        assert!(x.approx_eq(&x, &BigInt::zero()));
+        assert!(!x.approx_eq(&x, &BigInt::from(-1)));
+        let z: Element<R> = Vector::from(vec![3838, -3840, 0, 17]).into();
+        assert!(!x.approx_eq(&z, &BigInt::one()));
+        assert!(x.approx_eq(&z, &BigInt::from(2)));
+    }
+
+    #[test]
+    fn reference_operators() {
+        type R = Cyclotomic<U16, Char7>;
+        let mut rng = rand::thread_rng();
+        let a: Element<R> =
+            Vector::from(rng.gen::<[i64; 16]>().to_vec()).into();
+        let b: Element<R> =
+            Vector::from(rng.gen::<[i64; 16]>().to_vec()).into();
+        assert_eq!(&a + &b, a.clone() + &b);
+        assert_eq!(&a - &b, a.clone() - &b);
+        assert_eq!(&a * &b, R::mul(a.clone(), b.clone()));
+        // `a` and `b` are still usable.
+        assert_eq!(&(&a + &b) - &b, a);
+    }
+
+    #[test]
+    fn negate_mut() {
+        characteristic!(Char2, BigUint::from_u8(2).unwrap());
+        let mut one: ModularBigInt<Char2> = One::one();
+        one.negate_mut();
+        assert_eq!(one, One::one());
+
+        type R = Cyclotomic<U16, Char7>;
+        let v: Vector = rand::thread_rng().gen::<[i64; 16]>().to_vec().into();
+        let x: Element<R> = v.into();
+        let mut y = x.clone();
+        y.negate_mut();
+        assert_eq!(y, -x.clone());
+        assert_eq!(y.clone() + &x, Vector::from(vec![0]).into());
+        y.negate_mut();
+        assert_eq!(y, x);
+    }
+
+    #[test]
+    fn threshold_mask() {
+        type R = Cyclotomic<U4, Char7>;
+        let x: Element<R> = Vector::from(vec![3, -2, 1, 2]).into();
+        let mask = x.threshold_mask(&BigInt::from(1));
+        assert_eq!(mask, Vector::from(vec![1, 0, 0, 1]).into());
+        let mask = x.threshold_mask(&BigInt::from(-3));
+        assert_eq!(mask, Vector::from(vec![1, 1, 1, 1]).into());
+    }
+
+    #[test]
+    fn modulus_bits() {
+        assert_eq!(Cyclotomic::<U16, Char7>::modulus_bits(), 3);
+        assert_eq!(Cyclotomic::<U16, Char7681>::modulus_bits(), 13);
+        assert_eq!(Cyclotomic::<U16, CharZero>::modulus_bits(), 0);
+    }
+
+    #[test]
+    fn automorphism() {
+        type R = Cyclotomic<U4, Char7>;
+        // 1 + 2X + 3X³ ↦ 1 + 2X³ + 3X⁹ = 1 + 3X + 2X³
+        let x: Element<R> = Vector::from(vec![1, 2, 0, 3]).into();
+        let want: Element<R> = Vector::from(vec![1, 3, 0, 2]).into();
+        assert_eq!(x.automorphism(3), want);
+        assert_eq!(x.automorphism(1), x);
+        assert_eq!(x.automorphism(9), x);
+        // The automorphism is multiplicative.
+        let y: Element<R> = Vector::from(vec![-1, 0, 2, 1]).into();
+        assert_eq!(
+            R::mul(x.clone(), y.clone()).automorphism(5),
+            R::mul(x.automorphism(5), y.automorphism(5))
+        );
+    }
+
+    #[test]
+    fn automorphism_map() {
+        type R = Cyclotomic<U16, Char7>;
+        let mut rng = rand::thread_rng();
+        for k in [1, 3, 5, 31, 33, 77] {
+            let map = AutomorphismMap::new(k, 16);
+            for _ in 0..3 {
+                let v: Vector = rng.gen::<[i64; 16]>().to_vec().into();
+                let x: Element<R> = v.into();
+                let mut want: Vec<i64> = vec![0; 16 * k];
+                for (i, c) in
+                    Vector::from(x.clone()).coordinates().iter().enumerate()
+                {
+                    want[i * k] = c.try_into().unwrap();
+                }
+                assert_eq!(map.apply(&x), x.automorphism(k));
+                assert_eq!(map.apply(&x), Vector::from(want).into());
+            }
+        }
+    }
+
+    #[test]
+    fn galois_group() {
+        for n in [1, 2, 4, 16, 64] {
+            // The generators reach every odd residue modulo 2n.
+            let mut reached = vec![1_usize];
+            let mut i = 0;
+            while i < reached.len() {
+                for g in galois_generators(n) {
+                    let k = reached[i] * g % (2 * n);
+                    if !reached.contains(&k) {
+                        reached.push(k);
+                    }
+                }
+                i += 1;
+            }
+            assert_eq!(reached.len(), n, "degree {}", n);
+        }
+
+        type R = Cyclotomic<U16, Char7681>;
+        let v: Vector = rand::thread_rng().gen::<[i64; 16]>().to_vec().into();
+        let x: Element<R> = v.into();
+        let rotate = |x: &Element<R>, steps: i64| {
+            x.automorphism(rotation_to_galois_exponent(steps, 16))
+        };
+        assert_eq!(rotation_to_galois_exponent(0, 16), 1);
+        assert_eq!(rotation_to_galois_exponent(1, 16), 5);
+        assert_eq!(rotation_to_galois_exponent(8, 16), 1);
+        assert_eq!(rotate(&rotate(&x, 3), 2), rotate(&x, 5));
+        assert_eq!(rotate(&rotate(&x, 3), -3), x);
+        assert_eq!(rotate(&x, -1), rotate(&x, 7));
+    }
+
+    #[test]
+    #[should_panic(expected = "degree 0 is not a power of two")]
+    fn galois_group_of_degree_zero() {
+        galois_generators(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "degree 0 is not a power of two")]
+    fn automorphism_map_of_degree_zero() {
+        AutomorphismMap::new(3, 0);
+    }
+
+    #[test]
+    fn frobenius() {
+        type R = Cyclotomic<U4, Char7>;
+        let v: Vector = rand::thread_rng().gen::<[i64; 4]>().to_vec().into();
+        let x: Element<R> = v.into();
+        let power = |e: usize| Element::product(&vec![x.clone(); e]);
+        // 7 ≡ 7 mod 8, so the Frobenius map is X ↦ X⁷.
+        assert_eq!(x.frobenius(1), x.automorphism(7));
+        assert_eq!(x.frobenius(1), power(7));
+        assert_eq!(x.frobenius(2), x);
+
+        // 97 ≡ 1 mod 32: the ring splits completely and every slot is fixed.
+        characteristic!(Char97, BigUint::from_u8(97).unwrap());
+        type S = Cyclotomic<U16, Char97>;
+        let v: Vector = rand::thread_rng().gen::<[i64; 16]>().to_vec().into();
+        let y: Element<S> = v.into();
+        assert_eq!(y.frobenius(1), y);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "the Frobenius map needs an odd characteristic, got 2"
+    )]
+    fn frobenius_in_characteristic_two() {
+        characteristic!(Char2, BigUint::from_u8(2).unwrap());
+        Element::<Cyclotomic<U4, Char2>>::from(Vector::from(vec![1, 1]))
+            .frobenius(1);
+    }
+
+    #[test]
+    fn expansion_factors() {
+        type R = Cyclotomic<U16, CharZero>;
+        assert_eq!(R::expansion_factor(), 16.0);
+        assert_eq!(R::canonical_expansion_factor(), 4.0);
+
+        let mut rng = rand::thread_rng();
+        let a: Vec<i64> = (0..16).map(|_| rng.gen_range(-100..=100)).collect();
+        let b: Vec<i64> = (0..16).map(|_| rng.gen_range(-100..=100)).collect();
+        let x: Element<R> = Vector::from(a).into();
+        let y: Element<R> = Vector::from(b).into();
+        let z = R::mul(x.clone(), y.clone());
+        let bound = x.norm_infinity() * y.norm_infinity() * 16_u32;
+        assert!(z.norm_infinity() <= bound);
+        let can = x.canonical_norm() * y.canonical_norm();
+        assert!(z.canonical_norm() <= can * (1.0 + 1e-9));
+    }
+
+    #[test]
+    fn bit_length() {
+        type R = Cyclotomic<U4, CharZero>;
+        let small: Element<R> = Vector::from(vec![0, -8, 7]).into();
+        assert_eq!(small.bit_length(), 4);
+        // Two blocks of i64::MAX add up past i64::MAX in the constant term.
+        let v = vec![i64::MAX, 0, 0, 0, i64::MIN, 0, 0, 0];
+        let x: Element<R> = Vector::from(v).into();
+        assert!(x.bit_length() > 63);
+        let zero: Element<R> = Vector::from(vec![0]).into();
+        assert_eq!(zero.bit_length(), 0);
+    }
+
+    #[test]
+    fn coefficients_mod() {
+        type R = Cyclotomic<U16, Char257>;
+        let v: Vector = rand::thread_rng().gen::<[i64; 16]>().to_vec().into();
+        let x: Element<R> = v.into();
+        let parities: Vec<BigInt> = Vector::from(x.clone())
+            .coordinates()
+            .iter()
+            .map(|c| c.mod_floor(&BigInt::from(2)))
+            .collect();
+        assert_eq!(x.coefficients_mod(&BigUint::from(2_u32)), parities);
+
+        let y: Element<R> = Vector::from(vec![101, -101, 128, 4]).into();
+        let want: Vec<i64> = vec![1, -1, -2, -1];
+        let mut want: Vec<BigInt> =
+            want.into_iter().map(BigInt::from).collect();
+        want.resize(16, Zero::zero());
+        assert_eq!(y.coefficients_mod(&BigUint::from(5_u32)), want);
+    }
+
+    #[test]
+    fn coefficients_as_f64() {
+        type R = Cyclotomic<U4, Char2p61m1>;
+        let scale = 2_f64.powi(10);
+        let x: Element<R> = Vector::from(vec![3584, -512, 1]).into();
+        assert_eq!(
+            x.coefficients_as_f64(scale),
+            vec![3.5, -0.5, 1.0 / 1024.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn rescale_by() {
+        type R = Cyclotomic<U4, Char2p61m1>;
+        let scale = |bits: u32| BigInt::from(2).pow(bits);
+        let decode = |x: &Element<R>, bits: u32| -> Vec<BigInt> {
+            Vector::from(x.clone())
+                .coordinates()
+                .iter()
+                .map(|c| {
+                    let twice: BigInt = c * 2 + scale(bits);
+                    twice.div_floor(&(scale(bits) * 2))
+                })
+                .collect()
+        };
+        // 3 - 5X, encoded at scale 2⁴⁰ with a little noise.
+        let encoded: Vector =
+            vec![&scale(40) * 3 + 12345, &scale(40) * -5 - 999].into();
+        let x: Element<R> = encoded.into();
+        let y = x.rescale_by(&scale(10));
+        let want: Vec<BigInt> = vec![3.into(), (-5).into(), 0.into(), 0.into()];
+        assert_eq!(decode(&y, 30), want);
+
+        let z: Element<R> = Vector::from(vec![5, -5, 4, -4]).into();
+        let rescaled = z.rescale_by(&BigInt::from(2));
+        assert_eq!(rescaled, Vector::from(vec![3, -3, 2, -2]).into());
+        let negated = z.rescale_by(&BigInt::from(-2));
+        assert_eq!(negated, Vector::from(vec![-3, 3, -2, 2]).into());
+    }
+
+    #[test]
+    fn parse_and_display() {
+        type R = Cyclotomic<U4, Char7681>;
+        let cases: [(&str, Vec<i64>); 7] = [
+            ("3*X^2 + 2*X + 1", vec![1, 2, 3]),
+            ("-X^3+X", vec![0, 1, 0, -1]),
+            (" 5 ", vec![5]),
+            ("X^4 + 1", vec![0]),
+            ("2*X^5 - 7681*X - 3", vec![-3, 0, 0, 0, 0, 2]),
+            ("X - X", vec![0]),
+            ("-0", vec![0]),
+        ];
+        for (s, v) in cases.iter() {
+            let x: Element<R> = s.parse().unwrap();
+            assert_eq!(x, Vector::from(v.clone()).into(), "{}", s);
+            assert_eq!(x.to_string().parse::<Element<R>>().unwrap(), x);
+        }
+        let x: Element<R> = Vector::from(vec![1, -1, 0, -3840]).into();
+        assert_eq!(x.to_string(), "-3840*X^3 - X + 1");
+        assert_eq!(Element::<R>::from(Vector::from(vec![0])).to_string(), "0");
+
+        for s in ["", "3*", "X^", "2X", "3 + + X", "X^-1", "1.5", "--3", "Y^2"]
+        {
+            assert!(
+                matches!(
+                    s.parse::<Element<R>>(),
+                    Err(RlweError::InvalidPolynomial(_))
+                ),
+                "{:?}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn try_from_coefficients() {
+        type R = Cyclotomic<U4, Char7>;
+        let c: Vec<ModularBigInt<Char7>> =
+            (1..=4).map(|i| BigInt::from(i).into()).collect();
+        let x = Element::<R>::try_from(c.clone()).unwrap();
+        assert_eq!(x, Vector::from(vec![1, 2, 3, 4]).into());
+        assert_eq!(
+            Element::<R>::try_from(c[..3].to_vec()),
+            Err(RlweError::DimensionMismatch {
+                expected: 4,
+                found: 3
+            })
+        );
+        let mut long = c;
+        long.push(One::one());
+        assert_eq!(
+            Element::<R>::try_from(long),
+            Err(RlweError::DimensionMismatch {
+                expected: 4,
+                found: 5
+            })
+        );
+    }
+
+    #[test]
+    fn from_array() {
+        type R = Cyclotomic<U4, Char7>;
+        let x: Element<R> = Vector::from(vec![1, -2, 3]).into();
+        assert_eq!(Element::from_array(x.coefficients().clone()), x);
+    }
+
+    #[test]
+    fn vector_round_trip() {
+        type R = Cyclotomic<U4, Char7>;
+        let v: Vector = vec![0, -3, 3, 1].into();
+        let x: Element<R> = v.clone().into();
+        assert_eq!(Vector::from(x), v);
+
+        let long: Vector = vec![1, 2, 3, 4, 5, 6, 7, 8].into();
+        let y: Element<R> = long.into();
+        assert_eq!(Vector::from(y), vec![3, 3, 3, 3].into());
+    }
+
+    /// Reduces `v` by repeatedly substituting `Xⁿ = -1` in its leading term.
+    fn reference_reduction(mut v: Vec<i64>, degree: usize) -> Vec<i64> {
+        while v.len() > degree {
+            let top = v.pop().unwrap();
+            let k = v.len();
+            v[k - degree] -= top;
+        }
+        v
+    }
+
+    #[test]
+    fn convert_partial_blocks() {
+        fn check<C: Characteristic + Debug + PartialEq>() {
+            let degree = 4;
+            let mut rng = rand::thread_rng();
+            for len in [degree + 1, 2 * degree - 1, 3 * degree + 2] {
+                let v: Vec<i64> =
+                    (0..len).map(|_| rng.gen_range(-1000..1000)).collect();
+                let want: Element<Cyclotomic<U4, C>> =
+                    Vector::from(reference_reduction(v.clone(), degree)).into();
+                let got: Element<Cyclotomic<U4, C>> = Vector::from(v).into();
+                assert_eq!(got, want, "length {}", len);
+            }
+        }
+        check::<CharZero>();
+        check::<Char7>();
+    }
+
+    #[test]
+    fn char_zero_is_exact_beyond_i64() {
+        type Z = ModularBigInt<CharZero>;
+        let big = BigInt::from(i64::MAX);
+        let mut x: Z = big.clone().into();
+        let mut want = big.clone();
+        for i in 1..20 {
+            x += BigInt::from(i64::MAX).into();
+            x = x * BigInt::from(-i).into();
+            want += &big;
+            want *= -i;
+        }
+        assert_eq!(x.representant, want);
+        assert!(want > BigInt::from(i64::MAX) || want < BigInt::from(i64::MIN));
+
+        // Folding must not reduce the (huge) signed sums.
+        type R = Cyclotomic<U4, CharZero>;
+        let v: Vector = vec![big.clone(), Zero::zero(), Zero::zero(), -&big]
+            .into_iter()
+            .chain(vec![-&big, big.clone(), Zero::zero(), big.clone()])
+            .chain(vec![big.clone()])
+            .collect::<Vec<BigInt>>()
+            .into();
+        let x: Element<R> = v.into();
+        let want: Vector =
+            vec![&big * 3, -&big, Zero::zero(), -&big * 2].into();
+        assert_eq!(Vector::from(x.clone()), want);
+
+        // X³ · X³ = -X², with a coefficient well beyond i64.
+        let y: Element<R> = Vector::from(vec![0, 0, 0, i64::MAX]).into();
+        let square = R::mul(y.clone(), y);
+        let want: Vector =
+            vec![Zero::zero(), Zero::zero(), -&big * &big, Zero::zero()].into();
+        assert_eq!(Vector::from(square), want);
+    }
+
+    #[test]
+    fn convert_matches_per_coefficient_reduction() {
+        type R = Cyclotomic<U16, Char7681>;
+        let degree = 16;
+        let mut rng = rand::thread_rng();
+        let v: Vec<i64> = (0..1000).map(|_| rng.gen()).collect();
+        let mut slice: Vec<ModularBigInt<Char7681>> =
+            vec![Zero::zero(); degree];
+        for (i, x) in v.iter().enumerate() {
+            let c: ModularBigInt<Char7681> = BigInt::from(*x).into();
+            if i / degree % 2 == 0 {
+                slice[i % degree] += c;
+            } else {
+                slice[i % degree] -= c;
+            }
+        }
+        let got: Element<R> = Vector::from(v).into();
+        assert_eq!(got.coefficients().to_vec(), slice);
+    }
+
+    #[test]
+    fn convert_degenerate_vectors() {
+        type R = Cyclotomic<U4, Char7>;
+        let empty: Element<R> = Vector::from(Vec::<i64>::new()).into();
+        assert!(empty.coefficients().iter().all(|c| c.is_zero()));
+        assert_eq!(empty, Vector::from(vec![0, 0, 0, 0]).into());
+
+        let five: Element<R> = Vector::from(vec![5]).into();
+        assert_eq!(Vector::from(five), Vector::from(vec![-2, 0, 0, 0]));
+    }
+
+    #[test]
+    fn double_z() {
+        type R = Cyclotomic<U16, CharZero>;
+        let v: Vector = rand::thread_rng().gen::<[i64; 32]>().to_vec().into();
+        let x: Element<R> = v.into();
+        let sum = x.clone() + &x;
+        let want: Vec<ModularBigInt<CharZero>> = x
+            .coefficients()
+            .iter()
+            .map(|c| c.clone() + c.clone())
+            .collect();
+        assert_eq!(sum.coefficients().as_slice(), want);
+    }
+
+    #[test]
+    fn double_char_7() {
+        type R = Cyclotomic<U16, Char7>;
+        let v: Vector = rand::thread_rng().gen::<[i64; 32]>().to_vec().into();
+        let x: Element<R> = v.into();
+        let sum = x.clone() + &x;
+        let want: Vec<ModularBigInt<Char7>> = x
+            .coefficients()
+            .iter()
+            .map(|c| c.clone() + c.clone())
+            .collect();
+        assert_eq!(sum.coefficients().as_slice(), want);
+    }
+
+    #[test]
+    fn hadamard_square() {
+        type R = Cyclotomic<U16, CharZero>;
+        let v: Vector = rand::thread_rng().gen::<[i64; 32]>().to_vec().into();
+        let x: Element<R> = v.clone().into();
+        let y: Element<R> = v.into();
+        let hadamard_square = x.hadamard(&y);
+        let want: Vec<ModularBigInt<CharZero>> = y
+            .coefficients()
+            .iter()
             .map(|c| c.clone() * c.clone())
             .collect();
         assert_eq!(hadamard_square.coefficients().as_slice(), want);
     }
+
+    #[test]
+    fn linear_combination_two_terms() {
+        type R = Cyclotomic<U16, Char7>;
+        let mut rng = rand::thread_rng();
+        let a: Element<R> =
+            Vector::from(rng.gen::<[i64; 16]>().to_vec()).into();
+        let b: Element<R> =
+            Vector::from(rng.gen::<[i64; 16]>().to_vec()).into();
+        let alpha: ModularBigInt<Char7> = BigInt::from(rng.gen::<i64>()).into();
+        let beta: ModularBigInt<Char7> = BigInt::from(rng.gen::<i64>()).into();
+        let got = Element::linear_combination(&[
+            (alpha.clone(), &a),
+            (beta.clone(), &b),
+        ]);
+        let want = a.scalar_mul(&alpha) + &b.scalar_mul(&beta);
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn balanced_after_every_operation() {
+        type M = ModularBigInt<Char7>;
+        let balanced = |x: &M| {
+            x.representant > BigInt::from(-4) && x.representant <= 3.into()
+        };
+        let three: M = BigInt::from(3).into();
+        let minus_three: M = BigInt::from(-3).into();
+
+        // Intermediate representants 6, -6, 9 and -9 are all out of range.
+        let results = [
+            three.clone() + three.clone(),
+            minus_three.clone() + minus_three.clone(),
+            three.clone() * three.clone(),
+            three.clone() * minus_three.clone(),
+            -minus_three.clone(),
+        ];
+        for x in results.iter() {
+            assert!(balanced(x), "{:?} is not balanced", x);
+        }
+        assert_eq!(three.clone() + three.clone(), BigInt::from(-1).into());
+        assert_eq!(M::from(BigInt::from(-7)), Zero::zero());
+        assert_eq!(M::from(BigInt::from(7)), Zero::zero());
+
+        let mut acc = three.clone();
+        acc += three.clone();
+        assert!(balanced(&acc));
+        acc -= minus_three.clone();
+        acc -= minus_three;
+        assert!(balanced(&acc));
+        assert_eq!(acc, BigInt::from(-2).into());
+
+        type R = Cyclotomic<U4, Char7>;
+        let x: Element<R> = Vector::from(vec![3, 3, -3, -3]).into();
+        let y: Element<R> = Vector::from(vec![3, -4, -3, 10]).into();
+        for e in [x.clone() + &y, x.clone().hadamard(&y), x.scalar_mul(&three)]
+        {
+            assert!(e.coefficients().iter().all(balanced));
+        }
+        assert_eq!(x + &y, Vector::from(vec![-1, -1, 1, 0]).into());
+    }
+
+    #[cfg(feature = "debug-invariants")]
+    #[test]
+    fn debug_invariants_hold_through_a_chain() {
+        type M = ModularBigInt<Char7681>;
+        let mut rng = rand::thread_rng();
+        let mut acc: M = BigInt::from(rng.gen_range(-5000..5000)).into();
+        for _ in 0..1000 {
+            let x: M = BigInt::from(rng.gen_range(-5000..5000)).into();
+            acc = acc * x.clone() + x.clone();
+            acc -= x.pow(3);
+            acc += -x;
+            acc.negate_mut();
+        }
+        assert!(acc.representant.abs() <= BigInt::from(3840));
+    }
+
+    #[test]
+    fn balanced_rem() {
+        let q = BigUint::from(7681_u32);
+        let r = |x: i64| super::balanced_rem(&BigInt::from(x), &q);
+        assert_eq!(r(3840), BigInt::from(3840));
+        assert_eq!(r(-3840), BigInt::from(-3840));
+        assert_eq!(r(3841), BigInt::from(-3840));
+        assert_eq!(r(-3841), BigInt::from(3840));
+        assert_eq!(r(7681 * 5 + 3841), BigInt::from(-3840));
+        assert_eq!(r(-7681 * 5), BigInt::zero());
+
+        // With an even modulus, m/2 is kept and -m/2 maps to it.
+        let m = BigUint::from(10_u32);
+        let r = |x: i64| super::balanced_rem(&BigInt::from(x), &m);
+        assert_eq!(r(5), BigInt::from(5));
+        assert_eq!(r(-5), BigInt::from(5));
+        assert_eq!(r(6), BigInt::from(-4));
+        assert_eq!(r(-4), BigInt::from(-4));
+
+        let big = BigInt::from(i64::MAX) * 1000;
+        assert_eq!(super::balanced_rem(&big, &BigUint::zero()), big);
+    }
+
+    #[test]
+    fn reduce_once_matches_full_reduction() {
+        let q = 7681_i64;
+        let raw = |x: i64| ModularBigInt::<Char7681> {
+            representant: x.into(),
+            modulus: PhantomData,
+        };
+        for x in (-3 * q / 2..=3 * q / 2).chain([5 * q + 3, -4 * q - 1]) {
+            assert_eq!(raw(x).reduce_once(), BigInt::from(x).into(), "{}", x);
+        }
+        let z = ModularBigInt::<CharZero>::from(BigInt::from(q * q));
+        assert_eq!(z.reduce_once(), z);
+    }
+
+    #[test]
+    fn rotate_coefficients() {
+        type R = Cyclotomic<U4, Char7681>;
+        let x: Element<R> = Vector::from(vec![1, 2, 3, 4]).into();
+        assert_eq!(x.rotate_coefficients(4), x);
+        assert_eq!(x.rotate_coefficients(-8), x);
+        assert_eq!(
+            x.rotate_coefficients(1),
+            Vector::from(vec![4, 1, 2, 3]).into()
+        );
+        assert_eq!(
+            x.rotate_coefficients(-1),
+            Vector::from(vec![2, 3, 4, 1]).into()
+        );
+        assert_eq!(x.rotate_coefficients(3), x.rotate_coefficients(-1));
+        // X·x wraps 4X⁴ around to -4.
+        let shifted = R::mul(x.clone(), Element::monomial(One::one(), 1));
+        assert_eq!(shifted, Vector::from(vec![-4, 1, 2, 3]).into());
+        assert_ne!(shifted, x.rotate_coefficients(1));
+    }
+
+    #[test]
+    fn derivative() {
+        type R = Cyclotomic<U4, Char7>;
+        // (3 + 2X + 5X² + X³)' = 2 + 10X + 3X² ≡ 2 + 3X + 3X² mod 7.
+        let x: Element<R> = Vector::from(vec![3, 2, 5, 1]).into();
+        assert_eq!(x.derivative(), Vector::from(vec![2, 3, 3]).into());
+        let c: Element<R> = Vector::from(vec![6]).into();
+        assert_eq!(c.derivative(), R::zero());
+
+        type S = Cyclotomic<U16, CharZero>;
+        let mut v = vec![0; 16];
+        v[15] = -2;
+        let y: Element<S> = Vector::from(v).into();
+        let mut want = vec![0; 14];
+        want.push(-30);
+        assert_eq!(y.derivative(), Vector::from(want).into());
+    }
+
+    #[test]
+    fn negacyclic_invariant() {
+        assert!(check_negacyclic_invariant::<U4, Char7>());
+        assert!(check_negacyclic_invariant::<U16, CharZero>());
+        assert!(check_negacyclic_invariant::<typenum::consts::U1, Char257>());
+    }
+
+    #[test]
+    fn monomial_wraps_negacyclically() {
+        type R = Cyclotomic<U4, Char7>;
+        let x_n = Element::<R>::monomial(One::one(), 4);
+        let minus_one: Element<R> = Vector::from(vec![-1]).into();
+        assert_eq!(x_n, minus_one);
+
+        let x_7 = Element::<R>::monomial(One::one(), 7);
+        let want: Element<R> = Vector::from(vec![0, 0, 0, -1]).into();
+        assert_eq!(x_7, want);
+        assert_eq!(x_7.as_monomial(), Some((-ModularBigInt::one(), 3)));
+    }
+
+    #[test]
+    fn as_monomial() {
+        type R = Cyclotomic<U4, Char7>;
+        let x: Element<R> = Vector::from(vec![0, 0, 3]).into();
+        assert_eq!(x.as_monomial(), Some((BigInt::from(3).into(), 2)));
+        let y: Element<R> = Vector::from(vec![1, 0, 3]).into();
+        assert_eq!(y.as_monomial(), None);
+        let zero: Element<R> = Vector::from(vec![0]).into();
+        assert_eq!(zero.as_monomial(), None);
+    }
 }
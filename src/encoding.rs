@@ -0,0 +1,201 @@
+//! A streaming binary format for ring elements.
+//!
+//! All integers are little-endian. An element of `Cyclotomic<T, C>` is
+//! written as
+//!
+//! - the degree `n`, as a `u32`;
+//! - the characteristic, as a `u32` byte length followed by its unsigned
+//!   bytes (a single zero byte for `CharZero`);
+//! - `n` coefficients, each a `u32` byte length followed by the two's
+//!   complement bytes of its balanced representant.
+//!
+//! Coefficients go straight to the writer, one at a time, so encoding does
+//! not allocate a buffer for the whole element.
+
+use std::io::{Read, Write};
+
+use generic_array::{ArrayLength, GenericArray};
+use num_bigint::{BigInt, BigUint};
+use typenum::PowerOfTwo;
+
+use crate::cyclotomic::{Cyclotomic, ModularBigInt};
+use crate::error::RlweError;
+use crate::traits::{Characteristic, Element};
+
+impl<C, T> Element<Cyclotomic<T, C>>
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+{
+    /// Writes the element to `w` in the format described in the module
+    /// documentation.
+    pub fn write_to<W: Write>(&self, w: &mut W) -> std::io::Result<()> {
+        w.write_all(&(T::to_usize() as u32).to_le_bytes())?;
+        write_chunk(w, &C::to_biguint().to_bytes_le())?;
+        for c in self.coefficients.iter() {
+            write_chunk(w, &c.representant().to_signed_bytes_le())?;
+        }
+        Ok(())
+    }
+
+    /// Reads an element written by `write_to`. The degree and characteristic
+    /// in the header must match the ring, and every coefficient must be a
+    /// balanced representant; otherwise `RlweError::InvalidEncoding` is
+    /// returned.
+    pub fn read_from<Rd: Read>(r: &mut Rd) -> Result<Self, RlweError> {
+        let degree = read_u32(r)? as usize;
+        if degree != T::to_usize() {
+            return Err(RlweError::InvalidEncoding(format!(
+                "degree {} does not match {}",
+                degree,
+                T::to_usize()
+            )));
+        }
+        let ch = C::to_biguint();
+        // Anything longer than our own characteristic cannot match it.
+        let modulus_limit = Some(ch.to_bytes_le().len());
+        let modulus = BigUint::from_bytes_le(&read_chunk(r, modulus_limit)?);
+        if modulus != ch {
+            return Err(RlweError::InvalidEncoding(format!(
+                "characteristic {} does not match {}",
+                modulus, ch
+            )));
+        }
+        // A balanced representant never needs more bytes than the modulus
+        // plus a sign byte.
+        let limit = (ch.bits() != 0).then(|| ch.bits() as usize / 8 + 1);
+        let coefficients = (0..degree)
+            .map(|_| {
+                let v = BigInt::from_signed_bytes_le(&read_chunk(r, limit)?);
+                let c: ModularBigInt<C> = v.clone().into();
+                if c.representant() != &v {
+                    return Err(RlweError::InvalidEncoding(format!(
+                        "coefficient {} is not reduced modulo {}",
+                        v, ch
+                    )));
+                }
+                Ok(c)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Element {
+            coefficients: GenericArray::clone_from_slice(&coefficients),
+        })
+    }
+}
+
+fn write_chunk<W: Write>(w: &mut W, bytes: &[u8]) -> std::io::Result<()> {
+    w.write_all(&(bytes.len() as u32).to_le_bytes())?;
+    w.write_all(bytes)
+}
+
+fn read_u32<Rd: Read>(r: &mut Rd) -> Result<u32, RlweError> {
+    let mut buf = [0; 4];
+    r.read_exact(&mut buf).map_err(truncated)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+/// Reads a length-prefixed chunk, rejecting lengths above `limit` before
+/// reading any of it.
+fn read_chunk<Rd: Read>(
+    r: &mut Rd,
+    limit: Option<usize>,
+) -> Result<Vec<u8>, RlweError> {
+    let len = read_u32(r)? as usize;
+    if limit.is_some_and(|limit| len > limit) {
+        return Err(RlweError::InvalidEncoding(format!(
+            "chunk of {} bytes exceeds the limit of {}",
+            len,
+            limit.unwrap_or_default()
+        )));
+    }
+    let mut bytes = Vec::new();
+    r.take(len as u64)
+        .read_to_end(&mut bytes)
+        .map_err(truncated)?;
+    if bytes.len() != len {
+        return Err(RlweError::InvalidEncoding(
+            "unexpected end of input".into(),
+        ));
+    }
+    Ok(bytes)
+}
+
+fn truncated(e: std::io::Error) -> RlweError {
+    RlweError::InvalidEncoding(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use num_traits::{FromPrimitive, One};
+    use rand::Rng;
+    use typenum::consts::{U16, U4};
+
+    use super::*;
+    use crate::characteristic;
+    use crate::traits::{CharZero, Vector};
+
+    characteristic!(Char7681, BigUint::from_u16(7681).unwrap());
+    characteristic!(Char2p61m1, (BigUint::one() << 61) - 1_u32);
+
+    fn round_trip<C: Characteristic + std::fmt::Debug + PartialEq>() {
+        type T = U16;
+        let mut rng = rand::thread_rng();
+        let v: Vec<i64> = (0..16).map(|_| rng.gen()).collect();
+        let x: Element<Cyclotomic<T, C>> = Vector::from(v).into();
+        let mut cursor = Cursor::new(Vec::new());
+        x.write_to(&mut cursor).unwrap();
+        cursor.set_position(0);
+        assert_eq!(Element::read_from(&mut cursor).unwrap(), x);
+        assert_eq!(cursor.position() as usize, cursor.get_ref().len());
+    }
+
+    #[test]
+    fn write_and_read() {
+        round_trip::<Char7681>();
+        round_trip::<Char2p61m1>();
+        round_trip::<CharZero>();
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        type R = Cyclotomic<U4, Char7681>;
+        let x: Element<R> = Vector::from(vec![1, -2, 3840, -3840]).into();
+        let mut bytes = Vec::new();
+        x.write_to(&mut bytes).unwrap();
+
+        for len in 0..bytes.len() {
+            let result = Element::<R>::read_from(&mut &bytes[..len]);
+            assert!(matches!(result, Err(RlweError::InvalidEncoding(_))));
+        }
+        let wider =
+            Element::<Cyclotomic<U16, Char7681>>::read_from(&mut &bytes[..]);
+        assert!(matches!(wider, Err(RlweError::InvalidEncoding(_))));
+        let other =
+            Element::<Cyclotomic<U4, Char2p61m1>>::read_from(&mut &bytes[..]);
+        assert!(matches!(other, Err(RlweError::InvalidEncoding(_))));
+
+        // The last coefficient, -3840, is replaced by 3841.
+        let mut unreduced = bytes.clone();
+        let end = unreduced.len();
+        unreduced[end - 2..].copy_from_slice(&3841_i16.to_le_bytes());
+        let result = Element::<R>::read_from(&mut &unreduced[..]);
+        assert!(matches!(result, Err(RlweError::InvalidEncoding(_))));
+
+        // Huge length prefixes, for a coefficient or for the characteristic,
+        // are rejected without reading further.
+        for header in [4 + 4 + 2, 4] {
+            let mut oversized = bytes[..header].to_vec();
+            oversized.extend_from_slice(&u32::MAX.to_le_bytes());
+            let result = Element::<R>::read_from(&mut &oversized[..]);
+            assert_eq!(
+                result,
+                Err(RlweError::InvalidEncoding(format!(
+                    "chunk of {} bytes exceeds the limit of 2",
+                    u32::MAX
+                )))
+            );
+        }
+    }
+}
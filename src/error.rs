@@ -0,0 +1,51 @@
+use std::fmt::{Display, Formatter};
+
+use num_bigint::BigUint;
+
+/// The errors returned by the fallible operations of this crate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RlweError {
+    /// A collection of coefficients or evaluations does not have the length
+    /// required by the ring degree.
+    DimensionMismatch { expected: usize, found: usize },
+    /// The modulus is not a prime `p ≡ 1 mod 2n`, so the ring of degree `n`
+    /// does not support the number theoretic transform.
+    UnsupportedModulus { modulus: BigUint, degree: usize },
+    /// Projecting an element into a smaller ring would discard nonzero
+    /// coefficients.
+    LossyProjection,
+    /// A serialized element could not be read: the input is truncated, was
+    /// written for another ring, or holds an unreduced coefficient.
+    InvalidEncoding(String),
+    /// A string is not a polynomial of the form `3*X^2 - X + 1`.
+    InvalidPolynomial(String),
+}
+
+impl Display for RlweError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            RlweError::DimensionMismatch { expected, found } => {
+                write!(f, "expected {} values, found {}", expected, found)
+            }
+            RlweError::UnsupportedModulus { modulus, degree } => write!(
+                f,
+                "modulus {} does not support the NTT in degree {}: it must be \
+                 a prime congruent to 1 mod {}",
+                modulus,
+                degree,
+                2 * degree
+            ),
+            RlweError::LossyProjection => {
+                write!(f, "projection would discard nonzero coefficients")
+            }
+            RlweError::InvalidEncoding(reason) => {
+                write!(f, "invalid encoding: {}", reason)
+            }
+            RlweError::InvalidPolynomial(reason) => {
+                write!(f, "invalid polynomial: {}", reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RlweError {}
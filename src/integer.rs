@@ -0,0 +1,224 @@
+use generic_array::ArrayLength;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Signed, Zero};
+use typenum::PowerOfTwo;
+
+use crate::cyclotomic::{Cyclotomic, ModularBigInt};
+use crate::traits::{CharZero, Element, Vector};
+
+/// Algorithms specific to the integer ring `ℤ[X]/(Xⁿ+1)`.
+impl<T> Element<Cyclotomic<T, CharZero>>
+where
+    T: ArrayLength<ModularBigInt<CharZero>> + PowerOfTwo,
+{
+    /// Returns the greatest common divisor in `ℤ[X]` of the two elements,
+    /// seen as polynomials of degree less than `n`. The result is normalized
+    /// to have a positive leading coefficient; `gcd(0, 0)` is zero.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let a = trim(Vector::from(self.clone()).coordinates().clone());
+        let b = trim(Vector::from(other.clone()).coordinates().clone());
+        let content = content(&a).gcd(&content(&b));
+        let (mut a, mut b) = (primitive_part(a), primitive_part(b));
+        while !b.is_empty() {
+            let r = primitive_part(pseudo_remainder(&a, &b));
+            a = b;
+            b = r;
+        }
+        let g: Vec<BigInt> = a.into_iter().map(|c| c * &content).collect();
+        Vector::from(g).into()
+    }
+
+    /// Returns the remainder of the long division of the element, seen as a
+    /// polynomial of degree less than `n`, by a monic `divisor` given from
+    /// the constant coefficient up. The remainder has `deg(divisor)`
+    /// coefficients.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is not monic.
+    pub fn rem_poly(&self, divisor: &[BigInt]) -> Vec<BigInt> {
+        let a = Vector::from(self.clone()).coordinates().clone();
+        monic_remainder(&a, divisor)
+    }
+
+    /// Returns the resultant `Res(Xⁿ+1, a) = ∏ a(ζ)`, the product running over
+    /// the roots `ζ` of `Xⁿ+1`. It is the determinant of multiplication by `a`,
+    /// and `a` is invertible modulo a prime `p` exactly when `p` does not
+    /// divide it.
+    pub fn resultant_with_modulus(&self) -> BigInt {
+        let matrix = self
+            .to_multiplication_matrix()
+            .into_iter()
+            .map(|row| row.iter().map(|x| x.representant().clone()).collect())
+            .collect();
+        determinant(matrix)
+    }
+}
+
+/// Removes the leading zero coefficients.
+fn trim(mut a: Vec<BigInt>) -> Vec<BigInt> {
+    while a.last().is_some_and(Zero::is_zero) {
+        a.pop();
+    }
+    a
+}
+
+/// The gcd of the coefficients, which is zero for the zero polynomial.
+fn content(a: &[BigInt]) -> BigInt {
+    a.iter().fold(BigInt::zero(), |acc, c| acc.gcd(c))
+}
+
+/// Divides by the content, making the leading coefficient positive.
+fn primitive_part(a: Vec<BigInt>) -> Vec<BigInt> {
+    let mut c = content(&a);
+    if c.is_zero() {
+        return a;
+    }
+    if a.last().is_some_and(Signed::is_negative) {
+        c = -c;
+    }
+    a.into_iter().map(|x| x / &c).collect()
+}
+
+/// Returns the remainder of `lc(b)^k · a` divided by `b`, for `k` large
+/// enough that the division is exact over ℤ. `b` must be nonzero and
+/// trimmed.
+fn pseudo_remainder(a: &[BigInt], b: &[BigInt]) -> Vec<BigInt> {
+    let mut r = a.to_vec();
+    let lead = b.last().expect("division by zero polynomial");
+    while r.len() >= b.len() {
+        let top = r.last().unwrap().clone();
+        let shift = r.len() - b.len();
+        for x in r.iter_mut() {
+            *x *= lead;
+        }
+        for (i, y) in b.iter().enumerate() {
+            r[shift + i] -= &top * y;
+        }
+        r = trim(r);
+    }
+    r
+}
+
+/// Remainder of `a` by a monic `divisor`, padded to `deg(divisor)`
+/// coefficients.
+fn monic_remainder(a: &[BigInt], divisor: &[BigInt]) -> Vec<BigInt> {
+    let b = trim(divisor.to_vec());
+    assert!(
+        b.last().is_some_and(One::is_one),
+        "divisor {:?} is not monic",
+        divisor
+    );
+    // With a unit leading coefficient the pseudo-remainder is exact.
+    let mut r = pseudo_remainder(&trim(a.to_vec()), &b);
+    r.resize(b.len() - 1, Zero::zero());
+    r
+}
+
+/// Fraction-free (Bareiss) Gaussian elimination.
+fn determinant(mut m: Vec<Vec<BigInt>>) -> BigInt {
+    let n = m.len();
+    let mut sign = BigInt::one();
+    let mut prev = BigInt::one();
+    for k in 0..n {
+        if m[k][k].is_zero() {
+            match (k + 1..n).find(|&i| !m[i][k].is_zero()) {
+                Some(i) => {
+                    m.swap(k, i);
+                    sign = -sign;
+                }
+                None => return Zero::zero(),
+            }
+        }
+        for i in k + 1..n {
+            for j in k + 1..n {
+                m[i][j] = (&m[i][j] * &m[k][k] - &m[i][k] * &m[k][j]) / &prev;
+            }
+        }
+        prev = m[k][k].clone();
+    }
+    sign * &m[n - 1][n - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use typenum::consts::{U16, U4};
+
+    use super::*;
+    use crate::traits::RlweRing;
+
+    type R = Cyclotomic<U4, CharZero>;
+
+    #[test]
+    fn resultant() {
+        // ∏(ζ + 2) = (-2)⁴ + 1 over the roots of X⁴+1.
+        let x: Element<R> = Vector::from(vec![2, 1]).into();
+        assert_eq!(x.resultant_with_modulus(), BigInt::from(17));
+        // 1 + X² takes the values 1 ± i, twice each.
+        let y: Element<R> = Vector::from(vec![1, 0, 1]).into();
+        assert_eq!(y.resultant_with_modulus(), BigInt::from(4));
+        // X is a unit, since X⁴ = -1.
+        let z: Element<R> = Vector::from(vec![0, 1]).into();
+        assert_eq!(z.resultant_with_modulus(), BigInt::one());
+        let zero: Element<R> = Vector::from(vec![0]).into();
+        assert!(zero.resultant_with_modulus().is_zero());
+        // The resultant is multiplicative.
+        assert_eq!(
+            R::mul(x.clone(), y.clone()).resultant_with_modulus(),
+            BigInt::from(68)
+        );
+    }
+
+    #[test]
+    fn rem_poly() {
+        type S = Cyclotomic<U16, CharZero>;
+        // Dividing an over-length vector by X¹⁶ + 1 is the ring's folding.
+        let v: Vec<BigInt> =
+            (0..50).map(|i| BigInt::from(i * i - 40 * i)).collect();
+        let mut cyclotomic = vec![BigInt::zero(); 17];
+        cyclotomic[0] = One::one();
+        cyclotomic[16] = One::one();
+        let folded: Element<S> = Vector::from(v.clone()).into();
+        assert_eq!(
+            monic_remainder(&v, &cyclotomic),
+            Vector::from(folded.clone()).coordinates().clone()
+        );
+        assert_eq!(
+            folded.rem_poly(&cyclotomic),
+            Vector::from(folded.clone()).coordinates().clone()
+        );
+
+        let x2_plus_1: Vec<BigInt> = vec![1.into(), 0.into(), 1.into()];
+        let other: Element<S> =
+            Vector::from(vec![3, -1, 4, 1, -5, 9, 2, -6]).into();
+        // X² ≡ -1 gives (3 - 4 - 5 - 2) + (-1 - 1 + 9 + 6)X.
+        assert_eq!(
+            other.rem_poly(&x2_plus_1),
+            vec![BigInt::from(-8), BigInt::from(13)]
+        );
+        // Division by X - 2 leaves the value at 2.
+        let x_minus_2: Vec<BigInt> = vec![(-2).into(), 1.into()];
+        assert_eq!(other.rem_poly(&x_minus_2), vec![BigInt::from(-407)]);
+    }
+
+    #[test]
+    fn gcd() {
+        type S = Cyclotomic<U16, CharZero>;
+        // (X + 1)(2X - 3) and (X + 1)(X² + 5), scaled by 6 and 4.
+        let a: Element<S> = Vector::from(vec![-18, -6, 12]).into();
+        let b: Element<S> = Vector::from(vec![20, 20, 4, 4]).into();
+        let want: Element<S> = Vector::from(vec![2, 2]).into();
+        assert_eq!(a.gcd(&b), want);
+        assert_eq!(b.gcd(&a), want);
+
+        let c: Element<S> = Vector::from(vec![1, 0, 1]).into();
+        let d: Element<S> = Vector::from(vec![-3, 0, 2]).into();
+        assert_eq!(c.gcd(&d), Vector::from(vec![1]).into());
+
+        let zero: Element<S> = Vector::from(vec![0]).into();
+        let neg: Element<S> = Vector::from(vec![3, -6]).into();
+        assert_eq!(zero.gcd(&neg), Vector::from(vec![-3, 6]).into());
+        assert_eq!(zero.gcd(&zero), zero);
+    }
+}
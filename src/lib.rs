@@ -1,4 +1,11 @@
 #![deny(warnings)]
 
 pub mod cyclotomic;
+pub mod encoding;
+pub mod error;
+pub mod integer;
+pub mod matrix;
+pub mod montgomery;
+pub mod ntt;
+pub mod sampling;
 pub mod traits;
@@ -0,0 +1,290 @@
+use std::fmt::{Debug, Formatter};
+use std::ops::Add;
+
+use generic_array::ArrayLength;
+use typenum::PowerOfTwo;
+
+use crate::cyclotomic::{Cyclotomic, ModularBigInt};
+use crate::error::RlweError;
+use crate::traits::{Characteristic, Element, RlweRing};
+
+/// A rectangular matrix of ring elements, stored row by row.
+pub struct RingMatrix<R: RlweRing> {
+    rows: Vec<Vec<Element<R>>>,
+    columns: usize,
+}
+
+// Derives would require `R` itself to be Clone, Debug and PartialEq, rather
+// than its elements.
+impl<R: RlweRing> Clone for RingMatrix<R>
+where
+    Element<R>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            rows: self.rows.clone(),
+            columns: self.columns,
+        }
+    }
+}
+
+impl<R: RlweRing> Debug for RingMatrix<R>
+where
+    Element<R>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.debug_list().entries(self.rows.iter()).finish()
+    }
+}
+
+impl<R: RlweRing> PartialEq for RingMatrix<R>
+where
+    Element<R>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.columns == other.columns && self.rows == other.rows
+    }
+}
+
+impl<R: RlweRing> RingMatrix<R> {
+    /// Builds a matrix from its rows, which must all have the same length.
+    pub fn new(rows: Vec<Vec<Element<R>>>) -> Result<Self, RlweError> {
+        let columns = rows.first().map_or(0, Vec::len);
+        if let Some(row) = rows.iter().find(|row| row.len() != columns) {
+            return Err(RlweError::DimensionMismatch {
+                expected: columns,
+                found: row.len(),
+            });
+        }
+        Ok(Self { rows, columns })
+    }
+
+    pub fn rows(&self) -> &Vec<Vec<Element<R>>> {
+        &self.rows
+    }
+
+    /// Returns the number of rows and columns.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.rows.len(), self.columns)
+    }
+
+    pub fn at(&self, i: usize, j: usize) -> &Element<R> {
+        &self.rows[i][j]
+    }
+}
+
+impl<C, T> RingMatrix<Cyclotomic<T, C>>
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+{
+    /// Returns the matrix product `self · other`. Each entry is a sum of
+    /// ring products, accumulated with `Element::sum_elements` so that it is
+    /// reduced only once.
+    pub fn mul(&self, other: &Self) -> Result<Self, RlweError> {
+        let (rows, inner) = self.dimensions();
+        let (other_rows, columns) = other.dimensions();
+        if inner != other_rows {
+            return Err(RlweError::DimensionMismatch {
+                expected: inner,
+                found: other_rows,
+            });
+        }
+        let product = (0..rows)
+            .map(|i| {
+                (0..columns)
+                    .map(|j| {
+                        Element::sum_elements((0..inner).map(|k| {
+                            Cyclotomic::mul(
+                                self.rows[i][k].clone(),
+                                other.rows[k][j].clone(),
+                            )
+                        }))
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(Self {
+            rows: product,
+            columns,
+        })
+    }
+
+    /// Returns the transposed matrix.
+    pub fn transpose(&self) -> Self {
+        let rows = (0..self.columns)
+            .map(|j| self.rows.iter().map(|row| row[j].clone()).collect())
+            .collect();
+        Self {
+            rows,
+            columns: self.rows.len(),
+        }
+    }
+}
+
+/// A vector of `D` ring elements, the elements of the module `R^D` used by
+/// module-LWE.
+pub struct ModuleElement<R: RlweRing, const D: usize> {
+    components: [Element<R>; D],
+}
+
+impl<R: RlweRing, const D: usize> Clone for ModuleElement<R, D>
+where
+    Element<R>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            components: self.components.clone(),
+        }
+    }
+}
+
+impl<R: RlweRing, const D: usize> Debug for ModuleElement<R, D>
+where
+    Element<R>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.debug_list().entries(self.components.iter()).finish()
+    }
+}
+
+impl<R: RlweRing, const D: usize> PartialEq for ModuleElement<R, D>
+where
+    Element<R>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.components == other.components
+    }
+}
+
+impl<R: RlweRing, const D: usize> ModuleElement<R, D> {
+    pub fn new(components: [Element<R>; D]) -> Self {
+        Self { components }
+    }
+
+    pub fn components(&self) -> &[Element<R>; D] {
+        &self.components
+    }
+}
+
+impl<C, T, const D: usize> ModuleElement<Cyclotomic<T, C>, D>
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+{
+    /// Returns the inner product `Σ aᵢ·bᵢ`, reduced only once per
+    /// coefficient.
+    pub fn dot(&self, other: &Self) -> Element<Cyclotomic<T, C>> {
+        Element::sum_elements(
+            self.components
+                .iter()
+                .zip(other.components.iter())
+                .map(|(a, b)| a * b),
+        )
+    }
+}
+
+impl<C, T, const D: usize> Add<&ModuleElement<Cyclotomic<T, C>, D>>
+    for ModuleElement<Cyclotomic<T, C>, D>
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+{
+    type Output = Self;
+
+    fn add(self, other: &Self) -> Self::Output {
+        let mut components = self.components;
+        for (a, b) in components.iter_mut().zip(other.components.iter()) {
+            *a = &*a + b;
+        }
+        Self { components }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use num_traits::FromPrimitive;
+    use rand::Rng;
+    use typenum::consts::U8;
+
+    use super::*;
+    use crate::characteristic;
+    use crate::traits::Vector;
+
+    characteristic!(Char7681, BigUint::from_u16(7681).unwrap());
+    type R = Cyclotomic<U8, Char7681>;
+
+    fn random() -> Element<R> {
+        let mut rng = rand::thread_rng();
+        let v: Vec<i64> = (0..8).map(|_| rng.gen()).collect();
+        Vector::from(v).into()
+    }
+
+    #[test]
+    fn mul() {
+        let (a, b, c, d) = (random(), random(), random(), random());
+        let (e, f, g, h) = (random(), random(), random(), random());
+        let x = RingMatrix::new(vec![
+            vec![a.clone(), b.clone()],
+            vec![c.clone(), d.clone()],
+        ])
+        .unwrap();
+        let y = RingMatrix::new(vec![
+            vec![e.clone(), f.clone()],
+            vec![g.clone(), h.clone()],
+        ])
+        .unwrap();
+        let m = |u: &Element<R>, v: &Element<R>| R::mul(u.clone(), v.clone());
+        let want = RingMatrix::new(vec![
+            vec![m(&a, &e) + &m(&b, &g), m(&a, &f) + &m(&b, &h)],
+            vec![m(&c, &e) + &m(&d, &g), m(&c, &f) + &m(&d, &h)],
+        ])
+        .unwrap();
+        assert_eq!(x.mul(&y).unwrap(), want);
+
+        // (xy)ᵀ = yᵀxᵀ, since the ring is commutative.
+        assert_eq!(
+            x.mul(&y).unwrap().transpose(),
+            y.transpose().mul(&x.transpose()).unwrap()
+        );
+    }
+
+    #[test]
+    fn module_element() {
+        let (a, b, c, d) = (random(), random(), random(), random());
+        let x = ModuleElement::new([a.clone(), b.clone()]);
+        let y = ModuleElement::new([c.clone(), d.clone()]);
+        let want = R::mul(a.clone(), c.clone()) + &R::mul(b.clone(), d.clone());
+        assert_eq!(x.dot(&y), want);
+        assert_eq!(x.dot(&y), y.dot(&x));
+
+        let sum = x.clone() + &y;
+        assert_eq!(sum.components(), &[a + &c, b + &d]);
+        assert_eq!(sum.dot(&x), x.dot(&x) + &y.dot(&x));
+    }
+
+    #[test]
+    fn dimensions() {
+        let row = vec![random(), random(), random()];
+        let x = RingMatrix::new(vec![row.clone(), row.clone()]).unwrap();
+        assert_eq!(x.dimensions(), (2, 3));
+        assert_eq!(x.transpose().dimensions(), (3, 2));
+        assert_eq!(x.transpose().at(2, 1), x.at(1, 2));
+        assert_eq!(x.mul(&x.transpose()).unwrap().dimensions(), (2, 2));
+        assert_eq!(
+            x.mul(&x),
+            Err(RlweError::DimensionMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            RingMatrix::new(vec![row.clone(), row[..2].to_vec()]),
+            Err(RlweError::DimensionMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,228 @@
+use core::marker::PhantomData;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::ops::{Add, Mul, Sub};
+use std::sync::Arc;
+
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+use crate::traits::Characteristic;
+
+/// The constants of Montgomery arithmetic modulo an odd `q`, with
+/// `R = 2ʳ > q`.
+#[derive(Debug, PartialEq, Eq)]
+struct MontgomeryParams {
+    q: BigUint,
+    r_bits: u64,
+    /// `R mod q`, the Montgomery form of one.
+    r_mod_q: BigUint,
+    /// `R² mod q`, used to enter Montgomery form with a single reduction.
+    r2_mod_q: BigUint,
+    /// `-q⁻¹ mod R`.
+    q_neg_inv: BigUint,
+}
+
+impl MontgomeryParams {
+    fn new(q: BigUint) -> Self {
+        assert!(
+            q.bit(0) && q > BigUint::one(),
+            "Montgomery form requires an odd modulus, got {}",
+            q
+        );
+        let r_bits = q.bits();
+        let r = BigUint::one() << r_bits;
+        // Newton iteration for q⁻¹ mod R: each step doubles the number of
+        // correct low bits, starting from q⁻¹ ≡ 1 mod 2.
+        let mut inv = BigUint::one();
+        let mut correct = 1;
+        while correct < r_bits {
+            let two_minus = (&r + 2_u32 - (&q * &inv) % &r) % &r;
+            inv = (inv * two_minus) % &r;
+            correct *= 2;
+        }
+        let q_neg_inv = (&r - inv) % &r;
+        let r_mod_q = &r % &q;
+        let r2_mod_q = (&r_mod_q * &r_mod_q) % &q;
+        Self {
+            q,
+            r_bits,
+            r_mod_q,
+            r2_mod_q,
+            q_neg_inv,
+        }
+    }
+
+    /// Returns the cached constants for the modulus `q`.
+    fn get(q: BigUint) -> Arc<Self> {
+        thread_local! {
+            static CACHE: RefCell<HashMap<BigUint, Arc<MontgomeryParams>>> =
+                RefCell::new(HashMap::new());
+        }
+        CACHE.with(|cache| {
+            cache
+                .borrow_mut()
+                .entry(q.clone())
+                .or_insert_with(|| Arc::new(Self::new(q)))
+                .clone()
+        })
+    }
+
+    /// Montgomery reduction: returns `t·R⁻¹ mod q` for `t < q·R`.
+    fn redc(&self, t: BigUint) -> BigUint {
+        let mask = (BigUint::one() << self.r_bits) - 1_u32;
+        let m = ((&t & &mask) * &self.q_neg_inv) & &mask;
+        let u = (t + m * &self.q) >> self.r_bits;
+        if u >= self.q {
+            u - &self.q
+        } else {
+            u
+        }
+    }
+}
+
+/// An integer modulo the given characteristic, stored in Montgomery form
+/// `x·R mod q`. Products are reduced with shifts and masks instead of a
+/// division by `q`, which pays off over long chains of multiplications,
+/// such as the butterflies of a transform. Convert with
+/// `ModularBigInt::to_montgomery` and `ModularBigInt::from_montgomery`.
+///
+/// The characteristic must be odd, so in particular not `CharZero`.
+#[derive(Clone, PartialEq)]
+pub struct MontInt<C: Characteristic> {
+    /// `x·R mod q`, in `[0, q)`.
+    value: BigUint,
+    params: Arc<MontgomeryParams>,
+    modulus: PhantomData<C>,
+}
+
+impl<C: Characteristic> Debug for MontInt<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        self.value.fmt(f)
+    }
+}
+
+impl<C: Characteristic> MontInt<C> {
+    /// Enters Montgomery form from a residue in `[0, q)`.
+    pub(crate) fn from_residue(x: BigUint) -> Self {
+        let params = MontgomeryParams::get(C::to_biguint());
+        let value = params.redc(x * &params.r2_mod_q);
+        Self {
+            value,
+            params,
+            modulus: PhantomData,
+        }
+    }
+
+    /// Leaves Montgomery form, returning a residue in `[0, q)`.
+    pub(crate) fn to_residue(&self) -> BigUint {
+        self.params.redc(self.value.clone())
+    }
+
+    /// The Montgomery form of one, `R mod q`.
+    pub fn one() -> Self {
+        let params = MontgomeryParams::get(C::to_biguint());
+        Self {
+            value: params.r_mod_q.clone(),
+            params,
+            modulus: PhantomData,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+}
+
+impl<C: Characteristic> Add for MontInt<C> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        let mut value = self.value + other.value;
+        if value >= self.params.q {
+            value -= &self.params.q;
+        }
+        Self { value, ..self }
+    }
+}
+
+impl<C: Characteristic> Sub for MontInt<C> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        let value = if self.value >= other.value {
+            self.value - other.value
+        } else {
+            self.value + &self.params.q - other.value
+        };
+        Self { value, ..self }
+    }
+}
+
+impl<C: Characteristic> Mul for MontInt<C> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        let value = self.params.redc(self.value * other.value);
+        Self { value, ..self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+    use num_traits::FromPrimitive;
+    use rand::Rng;
+
+    use super::*;
+    use crate::characteristic;
+    use crate::cyclotomic::ModularBigInt;
+
+    characteristic!(Char7681, BigUint::from_u16(7681).unwrap());
+    characteristic!(Char2p61m1, (BigUint::one() << 61) - 1_u32);
+
+    fn check<C: Characteristic + Debug + PartialEq>(bound: i64) {
+        let mut rng = rand::thread_rng();
+        for _ in 0..200 {
+            let a: ModularBigInt<C> =
+                BigInt::from(rng.gen_range(-bound..bound)).into();
+            let b: ModularBigInt<C> =
+                BigInt::from(rng.gen_range(-bound..bound)).into();
+            let (x, y) = (a.to_montgomery(), b.to_montgomery());
+            assert_eq!(ModularBigInt::from_montgomery(&x), a);
+            let product = x.clone() * y.clone();
+            assert_eq!(
+                ModularBigInt::from_montgomery(&product),
+                a.clone() * b.clone()
+            );
+            let sum = x.clone() + y.clone();
+            assert_eq!(
+                ModularBigInt::from_montgomery(&sum),
+                a.clone() + b.clone()
+            );
+            let difference = x.clone() - y;
+            assert_eq!(
+                ModularBigInt::from_montgomery(&difference),
+                a.clone() - b
+            );
+            assert_eq!(x.clone() * MontInt::one(), x);
+        }
+    }
+
+    #[test]
+    fn matches_modular_arithmetic() {
+        check::<Char7681>(7681);
+        check::<Char2p61m1>(i64::MAX);
+    }
+
+    #[test]
+    fn params() {
+        let p = MontgomeryParams::new(BigUint::from(7681_u32));
+        assert_eq!(p.r_bits, 13);
+        let r = BigUint::one() << 13;
+        assert_eq!((&p.q * &p.q_neg_inv + 1_u32) % &r, BigUint::zero());
+        assert_eq!(p.r_mod_q, BigUint::from(511_u32));
+        assert_eq!(MontgomeryParams::get(BigUint::from(7681_u32)).as_ref(), &p);
+    }
+}
@@ -0,0 +1,525 @@
+use core::marker::PhantomData;
+
+use generic_array::{ArrayLength, GenericArray};
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Zero};
+use typenum::PowerOfTwo;
+
+use crate::cyclotomic::{
+    check_negacyclic_invariant, Cyclotomic, ModularBigInt,
+};
+use crate::error::RlweError;
+use crate::traits::{Characteristic, Element, Vector};
+
+/// Precomputed data for the negacyclic number theoretic transform over
+/// `𝔽_p[X]/(Xⁿ+1)`, for a prime `p ≡ 1 mod 2n`.
+///
+/// The forward transform maps the coefficients of `a` to the evaluations
+/// `a(ψ), a(ψ³), ..., a(ψ²ⁿ⁻¹)`, where `ψ` is a primitive `2n`-th root of
+/// unity. Since `Xⁿ+1 = ∏(X - ψ²ʲ⁺¹)`, ring multiplication becomes pointwise
+/// multiplication of the evaluations.
+#[derive(Clone, Debug)]
+pub struct NttContext<T, C>
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+{
+    /// `ψⁱ` for `i` in `0..n`.
+    psi_powers: Vec<ModularBigInt<C>>,
+    /// `ψ⁻ⁱ` for `i` in `0..n`.
+    psi_inv_powers: Vec<ModularBigInt<C>>,
+    /// `ωⁱ` for `i` in `0..n/2`, where `ω = ψ²`.
+    omega_powers: Vec<ModularBigInt<C>>,
+    /// `ω⁻ⁱ` for `i` in `0..n/2`.
+    omega_inv_powers: Vec<ModularBigInt<C>>,
+    /// `n⁻¹ mod p`.
+    n_inv: ModularBigInt<C>,
+    degree: PhantomData<T>,
+}
+
+impl<T, C> NttContext<T, C>
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+{
+    /// Builds the context. Fails with `RlweError::UnsupportedModulus` if the
+    /// characteristic is not of the form `p ≡ 1 mod 2n`, or if no primitive
+    /// `2n`-th root of unity is found (e.g. because `p` is not prime).
+    pub fn new() -> Result<Self, RlweError> {
+        let n = T::to_usize();
+        debug_assert!(
+            check_negacyclic_invariant::<T, C>(),
+            "Xⁿ ≠ -1 in the ring of degree {}",
+            n
+        );
+        let unsupported = || RlweError::UnsupportedModulus {
+            modulus: C::to_biguint(),
+            degree: n,
+        };
+        let psi = primitive_root::<C>(2 * n).ok_or_else(unsupported)?;
+        let psi_inv = psi.inverse().ok_or_else(unsupported)?;
+        let n_inv = ModularBigInt::<C>::from(BigInt::from(n))
+            .inverse()
+            .ok_or_else(unsupported)?;
+        let powers = |base: &ModularBigInt<C>, len: usize| {
+            let mut acc = Vec::with_capacity(len);
+            let mut x = ModularBigInt::<C>::one();
+            for _ in 0..len {
+                acc.push(x.clone());
+                x = x * base.clone();
+            }
+            acc
+        };
+        Ok(Self {
+            psi_powers: powers(&psi, n),
+            psi_inv_powers: powers(&psi_inv, n),
+            omega_powers: powers(&(psi.clone() * psi), n / 2),
+            omega_inv_powers: powers(&(psi_inv.clone() * psi_inv), n / 2),
+            n_inv,
+            degree: PhantomData,
+        })
+    }
+
+    /// Transforms `n` coefficients into `n` evaluations, in place.
+    pub fn forward(&self, values: &mut [ModularBigInt<C>]) {
+        assert_eq!(values.len(), T::to_usize());
+        for (x, psi) in values.iter_mut().zip(self.psi_powers.iter()) {
+            *x = x.clone() * psi.clone();
+        }
+        cyclic_transform(values, &self.omega_powers);
+    }
+
+    /// Transforms `n` evaluations back into `n` coefficients, in place.
+    pub fn inverse(&self, values: &mut [ModularBigInt<C>]) {
+        assert_eq!(values.len(), T::to_usize());
+        cyclic_transform(values, &self.omega_inv_powers);
+        for (x, psi_inv) in values.iter_mut().zip(self.psi_inv_powers.iter()) {
+            *x = x.clone() * self.n_inv.clone() * psi_inv.clone();
+        }
+    }
+
+    /// Returns `n⁻¹ mod p`, computed once in `new` and applied once per
+    /// coefficient by `inverse`.
+    pub fn inverse_scale(&self) -> &ModularBigInt<C> {
+        &self.n_inv
+    }
+
+    /// Returns the evaluations of `x` at the odd powers of `ψ`.
+    pub fn evaluations(
+        &self,
+        x: &Element<Cyclotomic<T, C>>,
+    ) -> Vec<ModularBigInt<C>> {
+        let mut values = x.coefficients().to_vec();
+        self.forward(&mut values);
+        values
+    }
+}
+
+/// Panics with a descriptive message unless the ring `Cyclotomic<T, C>`
+/// supports the NTT. Meant to be called when setting up a ring, to fail fast
+/// rather than deep inside a transform.
+pub fn assert_ntt_friendly<T, C>()
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+{
+    if let Err(e) = NttContext::<T, C>::new() {
+        panic!("{}", e);
+    }
+}
+
+/// Finds an element of order exactly `order`, a power of two dividing `p-1`.
+fn primitive_root<C: Characteristic>(order: usize) -> Option<ModularBigInt<C>> {
+    let p = C::to_biguint();
+    if !is_probable_prime(&p) {
+        return None;
+    }
+    let p_minus_one = p.clone() - 1_u32;
+    if !(p_minus_one.clone() % order).is_zero() {
+        return None;
+    }
+    let cofactor = p_minus_one / order;
+    // An element g yields a root of order `order` exactly when
+    // g^((p-1)/2) = -1, i.e. when g is a quadratic non-residue. The smallest
+    // one is tiny for every prime, so the search is bounded.
+    let mut g = BigUint::from(2_u32);
+    while g < p && g < BigUint::from(1_u32 << 16) {
+        let candidate = BigInt::from(g.modpow(&cofactor, &p));
+        let root: ModularBigInt<C> = candidate.into();
+        if (root.pow(order as u64 / 2) + One::one()).is_zero() {
+            return Some(root);
+        }
+        g += 1_u32;
+    }
+    None
+}
+
+/// Reverses the `log2(n)` low bits of `i`, for `n` a power of two.
+fn bit_reverse(i: usize, n: usize) -> usize {
+    i.reverse_bits()
+        .checked_shr(usize::BITS - n.trailing_zeros())
+        .unwrap_or(0)
+}
+
+/// Miller-Rabin test with the first thirteen primes as bases, which is
+/// deterministic below 3.3·10²⁴.
+fn is_probable_prime(p: &BigUint) -> bool {
+    const BASES: [u32; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+    let two = BigUint::from(2_u32);
+    if *p < two {
+        return false;
+    }
+    for base in BASES {
+        if *p == BigUint::from(base) {
+            return true;
+        }
+        if (p % base).is_zero() {
+            return false;
+        }
+    }
+    let p_minus_one = p - 1_u32;
+    let shift = p_minus_one.trailing_zeros().unwrap_or(0);
+    let d = &p_minus_one >> shift;
+    'bases: for base in BASES {
+        let mut x = BigUint::from(base).modpow(&d, p);
+        if x.is_one() || x == p_minus_one {
+            continue;
+        }
+        for _ in 1..shift {
+            x = x.modpow(&two, p);
+            if x == p_minus_one {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// In-place iterative Cooley-Tukey transform of length `n`, where `roots`
+/// holds the first `n/2` powers of a primitive `n`-th root of unity.
+fn cyclic_transform<C: Characteristic>(
+    values: &mut [ModularBigInt<C>],
+    roots: &[ModularBigInt<C>],
+) {
+    let n = values.len();
+    for i in 0..n {
+        let j = bit_reverse(i, n);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n {
+        let step = n / len;
+        for start in (0..n).step_by(len) {
+            for j in 0..len / 2 {
+                let u = values[start + j].clone();
+                let v = values[start + j + len / 2].clone()
+                    * roots[j * step].clone();
+                values[start + j] = u.clone() + v.clone();
+                values[start + j + len / 2] = u - v;
+            }
+        }
+        len *= 2;
+    }
+}
+
+impl<C, T> Element<Cyclotomic<T, C>>
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+{
+    /// Builds an element from its NTT evaluations, as returned by
+    /// `NttContext::evaluations`, by running the inverse transform.
+    pub fn from_ntt_evaluations(
+        ctx: &NttContext<T, C>,
+        mut evals: Vec<ModularBigInt<C>>,
+    ) -> Result<Self, RlweError> {
+        if evals.len() != T::to_usize() {
+            return Err(RlweError::DimensionMismatch {
+                expected: T::to_usize(),
+                found: evals.len(),
+            });
+        }
+        ctx.inverse(&mut evals);
+        evals.try_into()
+    }
+
+    /// Builds an element from a vector whose coordinates are listed in
+    /// bit-reversed order, as kept by some NTT libraries. The vector is
+    /// first padded or folded to length `n` as in `From<Vector>`.
+    pub fn from_vector_bitrev(v: Vector) -> Self {
+        let natural: Self = v.into();
+        let n = natural.coefficients.len();
+        let permuted: Vec<ModularBigInt<C>> = (0..n)
+            .map(|i| natural.coefficients[bit_reverse(i, n)].clone())
+            .collect();
+        let coefficients =
+            GenericArray::<ModularBigInt<C>, T>::clone_from_slice(&permuted);
+        Element { coefficients }
+    }
+
+    /// Returns the coefficients as a vector in bit-reversed order.
+    pub fn to_vector_bitrev(&self) -> Vector {
+        let n = self.coefficients.len();
+        let permuted: Vec<ModularBigInt<C>> = (0..n)
+            .map(|i| self.coefficients[bit_reverse(i, n)].clone())
+            .collect();
+        let coefficients =
+            GenericArray::<ModularBigInt<C>, T>::clone_from_slice(&permuted);
+        Element::<Cyclotomic<T, C>> { coefficients }.into()
+    }
+
+    /// Returns `xᵏ`, computed by raising each NTT evaluation to the `k`-th
+    /// power. This costs two transforms however large `k` is, where repeated
+    /// squaring in the coefficient domain needs `O(log k)` ring products.
+    pub fn pow_ntt(&self, ctx: &NttContext<T, C>, k: u64) -> Self {
+        let mut values: Vec<ModularBigInt<C>> =
+            ctx.evaluations(self).iter().map(|x| x.pow(k)).collect();
+        ctx.inverse(&mut values);
+        let coefficients =
+            GenericArray::<ModularBigInt<C>, T>::clone_from_slice(&values);
+        Element { coefficients }
+    }
+
+    /// Returns true if the element has a multiplicative inverse, that is, if
+    /// none of its NTT evaluations vanishes.
+    pub fn is_unit_ntt(&self, ctx: &NttContext<T, C>) -> bool {
+        ctx.evaluations(self).iter().all(|x| !x.is_zero())
+    }
+
+    /// Like `is_unit_ntt`, building the context on every call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the characteristic is not a prime `p ≡ 1 mod 2n`.
+    pub fn is_unit(&self) -> bool {
+        let ctx = NttContext::<T, C>::new().unwrap_or_else(|e| panic!("{}", e));
+        self.is_unit_ntt(&ctx)
+    }
+
+    /// Returns the multiplicative inverse of the element, computed by
+    /// inverting each NTT evaluation, or `None` if the element is not a unit.
+    pub fn ring_inverse_ntt(&self, ctx: &NttContext<T, C>) -> Option<Self> {
+        let mut values = ctx
+            .evaluations(self)
+            .iter()
+            .map(|x| x.inverse())
+            .collect::<Option<Vec<_>>>()?;
+        ctx.inverse(&mut values);
+        let coefficients =
+            GenericArray::<ModularBigInt<C>, T>::clone_from_slice(&values);
+        Some(Element { coefficients })
+    }
+
+    /// Like `ring_inverse_ntt`, building the context on every call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the characteristic is not a prime `p ≡ 1 mod 2n`.
+    pub fn ring_inverse(&self) -> Option<Self> {
+        let ctx = NttContext::<T, C>::new().unwrap_or_else(|e| panic!("{}", e));
+        self.ring_inverse_ntt(&ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_traits::FromPrimitive;
+    use rand::Rng;
+    use typenum::consts::{U1, U16, U2, U4};
+
+    use super::*;
+    use crate::characteristic;
+    use crate::traits::{CharZero, RlweRing, Vector};
+
+    characteristic!(Char7, BigUint::from_u8(7).unwrap());
+    characteristic!(Char97, BigUint::from_u8(97).unwrap());
+
+    fn random<T>() -> Element<Cyclotomic<T, Char97>>
+    where
+        T: ArrayLength<ModularBigInt<Char97>> + PowerOfTwo,
+    {
+        let mut rng = rand::thread_rng();
+        let v: Vec<i64> = (0..T::to_usize())
+            .map(|_| rng.gen_range(-48..=48))
+            .collect();
+        Vector::from(v).into()
+    }
+
+    #[test]
+    fn unsupported_modulus() {
+        assert_eq!(
+            NttContext::<U4, Char7>::new().unwrap_err(),
+            RlweError::UnsupportedModulus {
+                modulus: BigUint::from(7_u32),
+                degree: 4
+            }
+        );
+        assert!(NttContext::<U4, CharZero>::new().is_err());
+        assert!(NttContext::<U4, Char97>::new().is_ok());
+        assert!(NttContext::<U1, Char97>::new().is_ok());
+        // 97 * 193 ≡ 1 mod 32, but it is not prime.
+        characteristic!(Composite, BigUint::from_u32(97 * 193).unwrap());
+        assert!(NttContext::<U16, Composite>::new().is_err());
+        // A strong pseudoprime to every prime base up to 37, ≡ 1 mod 4.
+        characteristic!(
+            Pseudoprime,
+            BigUint::from_u128(318665857834031151167461).unwrap()
+        );
+        assert!(NttContext::<U2, Pseudoprime>::new().is_err());
+        assert_ntt_friendly::<U16, Char97>();
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus 7 does not support the NTT")]
+    fn assert_ntt_friendly_panics() {
+        assert_ntt_friendly::<U4, Char7>();
+    }
+
+    #[test]
+    fn forward_is_evaluation() {
+        type R = Cyclotomic<U4, Char97>;
+        let ctx = NttContext::<U4, Char97>::new().unwrap();
+        let x: Element<R> = random();
+        let evals = ctx.evaluations(&x);
+        for (j, e) in evals.iter().enumerate() {
+            let point = ctx.psi_powers[1].pow(2 * j as u64 + 1);
+            let mut want = ModularBigInt::<Char97>::zero();
+            for (i, c) in x.coefficients().iter().enumerate() {
+                want += c.clone() * point.pow(i as u64);
+            }
+            assert_eq!(*e, want);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let ctx = NttContext::<U16, Char97>::new().unwrap();
+        let x: Element<Cyclotomic<U16, Char97>> = random();
+        let mut values = ctx.evaluations(&x);
+        ctx.inverse(&mut values);
+        assert_eq!(values, x.coefficients().to_vec());
+    }
+
+    #[test]
+    fn pointwise_product_is_ring_product() {
+        type R = Cyclotomic<U16, Char97>;
+        let ctx = NttContext::<U16, Char97>::new().unwrap();
+        let x: Element<R> = random();
+        let y: Element<R> = random();
+        let mut values: Vec<_> = ctx
+            .evaluations(&x)
+            .into_iter()
+            .zip(ctx.evaluations(&y))
+            .map(|(a, b)| a * b)
+            .collect();
+        ctx.inverse(&mut values);
+        assert_eq!(values, R::mul(x, y).coefficients().to_vec());
+    }
+
+    #[test]
+    fn from_ntt_evaluations() {
+        type R = Cyclotomic<U16, Char97>;
+        let ctx = NttContext::<U16, Char97>::new().unwrap();
+        let x: Element<R> = random();
+        let y = Element::from_ntt_evaluations(&ctx, ctx.evaluations(&x));
+        assert_eq!(y, Ok(x));
+
+        let short = vec![ModularBigInt::zero(); 15];
+        assert_eq!(
+            Element::<R>::from_ntt_evaluations(&ctx, short),
+            Err(RlweError::DimensionMismatch {
+                expected: 16,
+                found: 15
+            })
+        );
+    }
+
+    #[test]
+    fn bit_reversed_vectors() {
+        type R = Cyclotomic<U4, Char97>;
+        let v: Vector = vec![0, 1, 2, 3].into();
+        let x = Element::<R>::from_vector_bitrev(v.clone());
+        assert_eq!(x, Vector::from(vec![0, 2, 1, 3]).into());
+        assert_ne!(x, v.clone().into());
+        assert_eq!(x.to_vector_bitrev(), v);
+
+        type S = Cyclotomic<U16, Char97>;
+        let y: Element<S> = random();
+        let w = y.to_vector_bitrev();
+        assert_eq!(
+            Element::<S>::from_vector_bitrev(w.clone()).to_vector_bitrev(),
+            w
+        );
+        assert_eq!(Element::from_vector_bitrev(w), y);
+    }
+
+    #[test]
+    fn ring_inverse() {
+        type R = Cyclotomic<U16, Char97>;
+        let one: Element<R> = Vector::from(vec![1]).into();
+        let mut x: Element<R> = random();
+        while !x.is_unit() {
+            x = random();
+        }
+        let x_inv = x.ring_inverse().unwrap();
+        assert_eq!(R::mul(x.clone(), x_inv.clone()), one);
+
+        let ctx = NttContext::<U16, Char97>::new().unwrap();
+        assert!(x.is_unit_ntt(&ctx));
+        assert_eq!(x.ring_inverse_ntt(&ctx), Some(x_inv));
+    }
+
+    #[test]
+    fn inverse_scale() {
+        let ctx = NttContext::<U16, Char97>::new().unwrap();
+        let n: ModularBigInt<Char97> = BigInt::from(16).into();
+        assert_eq!(n * ctx.inverse_scale().clone(), ModularBigInt::one());
+
+        let x = random::<U16>();
+        let mut values = x.coefficients().to_vec();
+        ctx.forward(&mut values);
+        ctx.inverse(&mut values);
+        assert_eq!(values.as_slice(), x.coefficients().as_slice());
+    }
+
+    #[test]
+    fn pow_ntt() {
+        type R = Cyclotomic<U16, Char97>;
+        let ctx = NttContext::<U16, Char97>::new().unwrap();
+        let x: Element<R> = random();
+        let cube = R::mul(R::mul(x.clone(), x.clone()), x.clone());
+        assert_eq!(x.pow_ntt(&ctx, 3), cube);
+        assert_eq!(x.pow_ntt(&ctx, 0), R::one());
+        assert_eq!(x.pow_ntt(&ctx, 1), x);
+        // Every unit evaluation has order dividing 96.
+        let mut u: Element<R> = random();
+        while !u.is_unit_ntt(&ctx) {
+            u = random();
+        }
+        assert_eq!(u.pow_ntt(&ctx, 96 * 1_000_000_007), R::one());
+    }
+
+    #[test]
+    fn non_unit() {
+        type R = Cyclotomic<U16, Char97>;
+        let ctx = NttContext::<U16, Char97>::new().unwrap();
+        let mut values = ctx.evaluations(&random::<U16>());
+        values[3] = Zero::zero();
+        ctx.inverse(&mut values);
+        let coefficients =
+            GenericArray::<ModularBigInt<Char97>, U16>::clone_from_slice(
+                &values,
+            );
+        let x: Element<R> = Element { coefficients };
+        assert!(!x.is_unit());
+        assert_eq!(x.ring_inverse(), None);
+        assert!(!x.is_unit_ntt(&ctx));
+        assert_eq!(x.ring_inverse_ntt(&ctx), None);
+
+        let zero: Element<R> = Vector::from(vec![0]).into();
+        assert!(!zero.is_unit());
+    }
+}
@@ -0,0 +1,171 @@
+use core::marker::PhantomData;
+
+use generic_array::{ArrayLength, GenericArray};
+use num_bigint::{BigInt, BigUint};
+use num_traits::Zero;
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use typenum::PowerOfTwo;
+
+use crate::cyclotomic::{Cyclotomic, ModularBigInt};
+use crate::traits::{Characteristic, Element};
+
+impl<C, T> Element<Cyclotomic<T, C>>
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+{
+    /// Samples an element whose coefficients are uniform in `[-bound, bound]`,
+    /// reduced modulo the characteristic. `gen_range` rejects out-of-range
+    /// draws internally, so the distribution is unbiased.
+    pub fn sample_bounded_uniform<G: Rng + ?Sized>(
+        rng: &mut G,
+        bound: u64,
+    ) -> Self {
+        let bound = i128::from(bound);
+        let coordinates: Vec<ModularBigInt<C>> = (0..T::to_usize())
+            .map(|_| BigInt::from(rng.gen_range(-bound..=bound)).into())
+            .collect();
+        let coefficients =
+            GenericArray::<ModularBigInt<C>, T>::clone_from_slice(&coordinates);
+        Element { coefficients }
+    }
+}
+
+/// A deterministic stream of coefficients uniform modulo the characteristic,
+/// expanded from a 32-byte seed with ChaCha20 (a counter-mode stream cipher).
+/// Coefficients are produced lazily, so an element can be regenerated from
+/// its seed incrementally, one coefficient at a time.
+pub struct PrgUniformSampler<C: Characteristic> {
+    prg: ChaCha20Rng,
+    modulus: BigUint,
+    bits: u64,
+    characteristic: PhantomData<C>,
+}
+
+impl<C: Characteristic> PrgUniformSampler<C> {
+    /// Creates a sampler from the given seed.
+    ///
+    /// # Panics
+    ///
+    /// Panics in characteristic zero, where there is no uniform distribution.
+    pub fn new(seed: [u8; 32]) -> Self {
+        let modulus = C::to_biguint();
+        assert!(
+            !modulus.is_zero(),
+            "uniform sampling needs a positive characteristic"
+        );
+        Self {
+            prg: ChaCha20Rng::from_seed(seed),
+            bits: modulus.bits(),
+            modulus,
+            characteristic: PhantomData,
+        }
+    }
+
+    /// Draws the next `n` coefficients of the stream as a ring element.
+    pub fn sample_element<T>(&mut self) -> Element<Cyclotomic<T, C>>
+    where
+        T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+    {
+        let coordinates: Vec<ModularBigInt<C>> =
+            self.by_ref().take(T::to_usize()).collect();
+        let coefficients =
+            GenericArray::<ModularBigInt<C>, T>::clone_from_slice(&coordinates);
+        Element { coefficients }
+    }
+}
+
+impl<C: Characteristic> Iterator for PrgUniformSampler<C> {
+    type Item = ModularBigInt<C>;
+
+    /// Draws `⌈log₂(q)⌉` bits at a time, rejecting values not below `q`.
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.bits.div_ceil(8) as usize;
+        let excess = len as u64 * 8 - self.bits;
+        let mut bytes = vec![0_u8; len];
+        loop {
+            self.prg.fill_bytes(&mut bytes);
+            bytes[len - 1] &= 0xff >> excess;
+            let x = BigUint::from_bytes_le(&bytes);
+            if x < self.modulus {
+                return Some(BigInt::from(x).into());
+            }
+        }
+    }
+}
+
+impl<C, T> Element<Cyclotomic<T, C>>
+where
+    C: Characteristic,
+    T: ArrayLength<ModularBigInt<C>> + PowerOfTwo,
+{
+    /// Samples a uniform element deterministically from a seed. This is the
+    /// first element drawn from `PrgUniformSampler::new(seed)`.
+    pub fn sample_uniform_from_seed(seed: [u8; 32]) -> Self {
+        PrgUniformSampler::new(seed).sample_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use num_traits::FromPrimitive;
+    use typenum::consts::{U1024, U16};
+
+    use super::*;
+    use crate::characteristic;
+    use crate::traits::Vector;
+
+    characteristic!(Char7681, BigUint::from_u16(7681).unwrap());
+    characteristic!(Char7, BigUint::from_u8(7).unwrap());
+
+    #[test]
+    fn seeded_stream_matches_batch() {
+        type R = Cyclotomic<U16, Char7681>;
+        let seed = [42_u8; 32];
+        let streamed: Vec<ModularBigInt<Char7681>> =
+            PrgUniformSampler::new(seed).take(16).collect();
+        let batch = Element::<R>::sample_uniform_from_seed(seed);
+        assert_eq!(batch.coefficients().to_vec(), streamed);
+
+        let mut sampler = PrgUniformSampler::<Char7681>::new(seed);
+        let first: Element<R> = sampler.sample_element();
+        let second: Element<R> = sampler.sample_element();
+        assert_eq!(first, batch);
+        assert_ne!(first, second);
+        assert_ne!(batch, Element::<R>::sample_uniform_from_seed([7; 32]));
+    }
+
+    #[test]
+    fn seeded_stream_is_uniform() {
+        let mut counts = [0_usize; 7];
+        for x in PrgUniformSampler::<Char7>::new([1; 32]).take(7000) {
+            let i = (-3..=3).position(|v| x == BigInt::from(v).into()).unwrap();
+            counts[i] += 1;
+        }
+        for count in counts {
+            assert!(count.abs_diff(1000) < 150);
+        }
+    }
+
+    #[test]
+    fn bounded_uniform() {
+        type R = Cyclotomic<U1024, Char7681>;
+        let mut rng = rand::thread_rng();
+        let mut counts = [0_usize; 7];
+        for _ in 0..20 {
+            let x = Element::<R>::sample_bounded_uniform(&mut rng, 3);
+            for c in Vector::from(x).coordinates() {
+                assert!(*c >= BigInt::from(-3) && *c <= BigInt::from(3));
+                counts[usize::try_from(c + 3).unwrap()] += 1;
+            }
+        }
+        // 20480 samples over 7 values: each count is about 2926, with a
+        // standard deviation of about 50.
+        let expected = 20 * 1024 / 7;
+        for count in counts {
+            assert!(count.abs_diff(expected) < expected / 10);
+        }
+    }
+}
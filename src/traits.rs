@@ -1,4 +1,4 @@
-use std::ops::{Add, Mul};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
 
 use generic_array::{ArrayLength, GenericArray};
 use num_bigint::{BigInt, BigUint};
@@ -49,6 +49,60 @@ pub trait RlweRing: Sized {
     fn characteristic() -> BigUint {
         <Self::Coefficient as FieldElement>::Char::to_biguint()
     }
+
+    /// Returns `⌈log₂(q)⌉` for the characteristic `q`, or 0 for `CharZero`.
+    #[must_use]
+    fn modulus_bits() -> usize {
+        let q = Self::characteristic();
+        if q.is_zero() {
+            return 0;
+        }
+        (q - 1_u32).bits() as usize
+    }
+
+    /// The worst-case expansion factor `n`: `‖a·b‖∞ ≤ n·‖a‖∞·‖b‖∞` for the
+    /// coefficient infinity norm.
+    #[must_use]
+    fn expansion_factor() -> f64 {
+        Self::degree() as f64
+    }
+
+    /// The expansion factor `√n` used by noise estimates in the canonical
+    /// embedding. The canonical norm is submultiplicative, and for
+    /// power-of-two cyclotomics an element with independent zero-mean
+    /// coefficients has a canonical norm of about `√n` times its coefficient
+    /// norm. Products of such elements therefore grow by about `√n`, rather
+    /// than the worst-case `n`.
+    #[must_use]
+    fn canonical_expansion_factor() -> f64 {
+        (Self::degree() as f64).sqrt()
+    }
+
+    /// The additive identity.
+    #[must_use]
+    fn zero() -> Element<Self> {
+        let slice: Vec<Self::Coefficient> =
+            (0..Self::degree()).map(|_| BigInt::zero().into()).collect();
+        Element {
+            coefficients: GenericArray::clone_from_slice(&slice),
+        }
+    }
+
+    /// The multiplicative identity, i.e. the constant polynomial `1`.
+    #[must_use]
+    fn one() -> Element<Self> {
+        let slice: Vec<Self::Coefficient> = (0..Self::degree())
+            .map(|i| BigInt::from(u8::from(i == 0)).into())
+            .collect();
+        Element {
+            coefficients: GenericArray::clone_from_slice(&slice),
+        }
+    }
+}
+
+/// Summation of an iterator of ring elements, reducing only once at the end.
+pub trait ElementSumExt<R: RlweRing>: Iterator<Item = Element<R>> {
+    fn sum_ring(self) -> Element<R>;
 }
 
 /// An element of a RlweRing.
@@ -58,6 +112,13 @@ pub struct Element<R: RlweRing> {
 }
 
 impl<R: RlweRing> Element<R> {
+    /// Wraps an array of coefficients, taking ownership of it.
+    pub fn from_array(
+        coefficients: GenericArray<R::Coefficient, R::Degree>,
+    ) -> Self {
+        Element { coefficients }
+    }
+
     pub fn coefficients(&self) -> &GenericArray<R::Coefficient, R::Degree> {
         &self.coefficients
     }
@@ -65,6 +126,25 @@ impl<R: RlweRing> Element<R> {
     pub fn at(&self, i: usize) -> &R::Coefficient {
         &self.coefficients[i]
     }
+
+    /// Returns the constant polynomial `c`.
+    pub fn constant(c: R::Coefficient) -> Self {
+        let mut e = R::zero();
+        e.coefficients[0] = c;
+        e
+    }
+
+    /// Returns the constant coefficient `a₀`.
+    pub fn constant_term(&self) -> R::Coefficient {
+        self.coefficients[0].clone()
+    }
+
+    /// Returns a copy of the element with its constant coefficient zeroed.
+    pub fn without_constant(&self) -> Self {
+        let mut coefficients = self.coefficients.clone();
+        coefficients[0] = BigInt::zero().into();
+        Element { coefficients }
+    }
 }
 
 impl<R: RlweRing> Add<&Element<R>> for Element<R>
@@ -91,6 +171,100 @@ where
     }
 }
 
+/// Adds an integer, reduced into the coefficient type, to the constant term.
+impl<R: RlweRing> AddAssign<&BigInt> for Element<R>
+where
+    R::Coefficient: Add<Output = R::Coefficient>,
+{
+    fn add_assign(&mut self, other: &BigInt) {
+        let c = self.coefficients[0].clone();
+        self.coefficients[0] = c + other.clone().into();
+    }
+}
+
+impl<R: RlweRing> Sub<&Element<R>> for Element<R>
+where
+    R::Coefficient: Sub,
+    Vec<R::Coefficient>: FromIterator<<R::Coefficient as Sub>::Output>,
+{
+    type Output = Self;
+
+    fn sub(self, other: &Self) -> Self::Output {
+        let slice: Vec<R::Coefficient> = self
+            .coefficients
+            .into_iter()
+            .zip(other.coefficients.clone())
+            .map(|(x, y)| x - y)
+            .collect();
+        let coeffs =
+            GenericArray::<R::Coefficient, R::Degree>::clone_from_slice(&slice);
+        Element::<R> {
+            coefficients: coeffs,
+        }
+    }
+}
+
+impl<R: RlweRing> Neg for Element<R>
+where
+    R::Coefficient: Neg,
+    Vec<R::Coefficient>: FromIterator<<R::Coefficient as Neg>::Output>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let slice: Vec<R::Coefficient> =
+            self.coefficients.into_iter().map(|x| -x).collect();
+        let coeffs =
+            GenericArray::<R::Coefficient, R::Degree>::clone_from_slice(&slice);
+        Element::<R> {
+            coefficients: coeffs,
+        }
+    }
+}
+
+impl<R: RlweRing> Add<&Element<R>> for &Element<R>
+where
+    Vec<R::Coefficient>: FromIterator<<R::Coefficient as Add>::Output>,
+{
+    type Output = Element<R>;
+
+    fn add(self, other: &Element<R>) -> Self::Output {
+        let lhs = Element::<R> {
+            coefficients: self.coefficients.clone(),
+        };
+        lhs + other
+    }
+}
+
+impl<R: RlweRing> Sub<&Element<R>> for &Element<R>
+where
+    R::Coefficient: Sub,
+    Vec<R::Coefficient>: FromIterator<<R::Coefficient as Sub>::Output>,
+{
+    type Output = Element<R>;
+
+    fn sub(self, other: &Element<R>) -> Self::Output {
+        let lhs = Element::<R> {
+            coefficients: self.coefficients.clone(),
+        };
+        lhs - other
+    }
+}
+
+impl<R: RlweRing> Mul<&Element<R>> for &Element<R> {
+    type Output = Element<R>;
+
+    fn mul(self, other: &Element<R>) -> Self::Output {
+        let lhs = Element::<R> {
+            coefficients: self.coefficients.clone(),
+        };
+        let rhs = Element::<R> {
+            coefficients: other.coefficients.clone(),
+        };
+        R::mul(lhs, rhs)
+    }
+}
+
 impl<R: RlweRing> Element<R>
 where
     Vec<R::Coefficient>: FromIterator<<R::Coefficient as Mul>::Output>,
@@ -111,6 +285,20 @@ where
             coefficients: coeffs,
         }
     }
+
+    /// Multiplies every coefficient by the given scalar.
+    pub fn scalar_mul(&self, scalar: &R::Coefficient) -> Self {
+        let slice: Vec<R::Coefficient> = self
+            .coefficients
+            .iter()
+            .map(|x| x.clone() * scalar.clone())
+            .collect();
+        let coeffs =
+            GenericArray::<R::Coefficient, R::Degree>::clone_from_slice(&slice);
+        Element::<R> {
+            coefficients: coeffs,
+        }
+    }
 }
 
 /// A vector is a collection of integers, and it can be used to instantiate an
@@ -147,3 +335,18 @@ impl From<Vec<BigInt>> for Vector {
         Self { coordinates }
     }
 }
+
+impl From<&[i64]> for Vector {
+    fn from(x: &[i64]) -> Self {
+        let coordinates: Vec<BigInt> = x.iter().map(|x| (*x).into()).collect();
+        Self { coordinates }
+    }
+}
+
+impl From<&[BigInt]> for Vector {
+    fn from(x: &[BigInt]) -> Self {
+        Self {
+            coordinates: x.to_vec(),
+        }
+    }
+}